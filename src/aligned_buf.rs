@@ -0,0 +1,130 @@
+use std::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// A heap allocated, zero-initialized byte buffer whose start is aligned to `ALIGN` bytes.
+///
+/// Unlike [`Vec<u8>`], which only guarantees an alignment of one, this buffer can be handed to
+/// APIs that reject unaligned memory, such as files opened with `O_DIRECT` or DMA engines.
+/// `ALIGN` must be a power of two, which is checked at compile time.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::AlignedBuf;
+///
+/// let mut buffer = AlignedBuf::<4096>::new(8192);
+/// assert_eq!(buffer.len(), 8192);
+/// assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+///
+/// buffer[0] = 0xAB;
+/// assert_eq!(buffer[..2], [0xAB, 0x00]);
+/// ```
+pub struct AlignedBuf<const ALIGN: usize> {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+impl<const ALIGN: usize> AlignedBuf<ALIGN> {
+    const VALID_ALIGN: () = assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+
+    /// Allocate a new zero-filled buffer of `len` bytes.
+    ///
+    /// # Panics
+    /// Panics if `len` rounded up to `ALIGN` exceeds [`isize::MAX`].
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        let () = Self::VALID_ALIGN;
+        let layout = Self::layout(len);
+        // SAFETY: The layout has a non-zero size.
+        let ptr = unsafe { alloc_zeroed(layout) };
+
+        match NonNull::new(ptr) {
+            Some(ptr) => Self { ptr, len },
+            None => handle_alloc_error(layout),
+        }
+    }
+
+    /// Return the length of the buffer in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the buffer has a length of zero.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the layout of the allocation backing a buffer of `len` bytes.
+    ///
+    /// At least one byte is allocated, so that empty buffers are aligned as well.
+    fn layout(len: usize) -> Layout {
+        Layout::from_size_align(len.max(1), ALIGN).expect("buffer size overflow")
+    }
+}
+
+impl<const ALIGN: usize> Clone for AlignedBuf<ALIGN> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new(self.len);
+        clone.copy_from_slice(self);
+        clone
+    }
+}
+
+impl<const ALIGN: usize> Debug for AlignedBuf<ALIGN> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const ALIGN: usize> Deref for AlignedBuf<ALIGN> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The pointer refers to an initialized allocation of at least `len` bytes.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<const ALIGN: usize> DerefMut for AlignedBuf<ALIGN> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The pointer refers to an initialized allocation of at least `len` bytes,
+        // which is uniquely borrowed through `self`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<const ALIGN: usize> AsRef<[u8]> for AlignedBuf<ALIGN> {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const ALIGN: usize> AsMut<[u8]> for AlignedBuf<ALIGN> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl<const ALIGN: usize> PartialEq for AlignedBuf<ALIGN> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<const ALIGN: usize> Eq for AlignedBuf<ALIGN> {}
+
+impl<const ALIGN: usize> Drop for AlignedBuf<ALIGN> {
+    fn drop(&mut self) {
+        // SAFETY: The pointer was allocated in `new` with the same layout.
+        unsafe { dealloc(self.ptr.as_ptr(), Self::layout(self.len)) }
+    }
+}
+
+// SAFETY: The buffer uniquely owns its allocation, like a `Box<[u8]>`.
+unsafe impl<const ALIGN: usize> Send for AlignedBuf<ALIGN> {}
+
+// SAFETY: Shared references only permit reading the bytes.
+unsafe impl<const ALIGN: usize> Sync for AlignedBuf<ALIGN> {}
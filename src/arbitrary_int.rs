@@ -0,0 +1,52 @@
+//! [`ReadFrom`] and [`WriteTo`] implementations for the integers of the `arbitrary-int` crate.
+//!
+//! An integer of `BITS` bits is encoded as its underlying primitive type, e.g. `u12` as `u16`.
+//! Reading values which exceed `BITS` bits results in an error of kind [`ErrorKind::InvalidData`].
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use ::arbitrary_int::UInt;
+
+use crate::{EncodedLen, Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+macro_rules! impl_uint {
+    ($($type:ty),*) => {
+        $(
+            impl<const BITS: usize> ReadFrom for UInt<$type, BITS> {
+                fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+                where
+                    R: Read + ?Sized,
+                {
+                    let value = <$type>::read_from(reader, endianness)?;
+                    Self::try_new(value).map_err(|_| {
+                        Error::new(
+                            ErrorKind::InvalidData,
+                            format!("value {value} exceeds {BITS} bits"),
+                        )
+                    })
+                }
+            }
+
+            impl<const BITS: usize> WriteTo for UInt<$type, BITS> {
+                fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+                where
+                    W: Write + ?Sized,
+                {
+                    self.value().write_to(writer, endianness)
+                }
+            }
+
+            impl<const BITS: usize> FixedEncodedLen for UInt<$type, BITS> {
+                const ENCODED_LEN: usize = <$type>::ENCODED_LEN;
+            }
+
+            impl<const BITS: usize> EncodedLen for UInt<$type, BITS> {
+                fn encoded_len(&self) -> usize {
+                    Self::ENCODED_LEN
+                }
+            }
+        )*
+    };
+}
+
+impl_uint!(u8, u16, u32, u64, u128);
@@ -0,0 +1,79 @@
+use std::io::{Read, Result};
+use std::sync::atomic::{self, Ordering};
+
+use crate::{Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+/// An atomic integer type, whose value can be read and written.
+///
+/// Atomics implement [`ReadFrom`] by reading their value into a new atomic.
+/// Since writing requires a memory ordering for loading the value, they are written with
+/// [`WriteNumExt::write_atomic`](crate::WriteNumExt::write_atomic) rather than [`WriteTo`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, ReadValueExt, WriteNumExt};
+/// use std::io::Cursor;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// let counter: AtomicU32 = Cursor::new([0x00, 0x00, 0x01, 0x00])
+///     .read_value(Endianness::Big)
+///     .unwrap();
+/// counter.fetch_add(1, Ordering::Relaxed);
+///
+/// let mut bytes = Vec::new();
+/// bytes
+///     .write_atomic(&counter, Ordering::Relaxed, Endianness::Little)
+///     .unwrap();
+/// assert_eq!(bytes, [0x01, 0x01, 0x00, 0x00]);
+/// ```
+pub trait AtomicInteger {
+    /// The underlying integer type.
+    type Integer: ReadFrom + WriteTo;
+
+    /// Create a new atomic holding `integer`.
+    fn from_integer(integer: Self::Integer) -> Self;
+
+    /// Load the value of the atomic with the given memory ordering.
+    fn load_integer(&self, ordering: Ordering) -> Self::Integer;
+}
+
+macro_rules! impl_atomic {
+    ($($bits:literal: $($atomic:ident($integer:ty)),+;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $bits)]
+            impl AtomicInteger for atomic::$atomic {
+                type Integer = $integer;
+
+                fn from_integer(integer: Self::Integer) -> Self {
+                    Self::new(integer)
+                }
+
+                fn load_integer(&self, ordering: Ordering) -> Self::Integer {
+                    self.load(ordering)
+                }
+            }
+
+            #[cfg(target_has_atomic = $bits)]
+            impl ReadFrom for atomic::$atomic {
+                fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+                where
+                    R: Read + ?Sized,
+                {
+                    <$integer>::read_from(reader, endianness).map(Self::new)
+                }
+            }
+
+            #[cfg(target_has_atomic = $bits)]
+            impl FixedEncodedLen for atomic::$atomic {
+                const ENCODED_LEN: usize = <$integer>::ENCODED_LEN;
+            }
+        )+)*
+    };
+}
+
+impl_atomic! {
+    "8": AtomicU8(u8), AtomicI8(i8);
+    "16": AtomicU16(u16), AtomicI16(i16);
+    "32": AtomicU32(u32), AtomicI32(i32);
+    "64": AtomicU64(u64), AtomicI64(i64);
+}
@@ -0,0 +1,108 @@
+use std::io::{Read, Result, Write};
+
+use ::base64::engine::general_purpose::{GeneralPurpose, STANDARD, URL_SAFE};
+use ::base64::read::DecoderReader;
+use ::base64::write::EncoderWriter;
+
+/// A reader that decodes base64 text from the underlying reader.
+///
+/// The decoder buffers its input, so the underlying reader may be read beyond the end
+/// of the decoded data.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Base64Reader, ReadBytesExt, ReadNumExt};
+///
+/// let mut reader = Base64Reader::new(&b"q83vQg=="[..]);
+/// assert_eq!(reader.read_array_exact().unwrap(), [0xAB, 0xCD, 0xEF, 0x42]);
+/// assert!(reader.read_bool().is_err());
+/// ```
+#[derive(Debug)]
+pub struct Base64Reader<R>(DecoderReader<'static, GeneralPurpose, R>)
+where
+    R: Read;
+
+impl<R> Base64Reader<R>
+where
+    R: Read,
+{
+    /// Create a new reader decoding standard base64 with padding.
+    pub fn new(reader: R) -> Self {
+        Self(DecoderReader::new(reader, &STANDARD))
+    }
+
+    /// Create a new reader decoding URL-safe base64 with padding.
+    pub fn url_safe(reader: R) -> Self {
+        Self(DecoderReader::new(reader, &URL_SAFE))
+    }
+
+    /// Return the underlying reader.
+    ///
+    /// Input that has been buffered, but not yet decoded, is lost.
+    pub fn into_inner(self) -> R {
+        self.0.into_inner()
+    }
+}
+
+impl<R> Read for Base64Reader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+/// A writer that encodes the bytes written to it as base64 text.
+///
+/// The encoded text is only complete after calling [`Base64Writer::finish`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Base64Writer, WriteNumExt};
+///
+/// let mut writer = Base64Writer::new(Vec::new());
+/// writer.write_bool(true).unwrap();
+/// writer.write_bool(false).unwrap();
+/// assert_eq!(writer.finish().unwrap(), b"AQA=");
+/// ```
+#[derive(Debug)]
+pub struct Base64Writer<W>(EncoderWriter<'static, GeneralPurpose, W>)
+where
+    W: Write;
+
+impl<W> Base64Writer<W>
+where
+    W: Write,
+{
+    /// Create a new writer encoding standard base64 with padding.
+    pub fn new(writer: W) -> Self {
+        Self(EncoderWriter::new(writer, &STANDARD))
+    }
+
+    /// Create a new writer encoding URL-safe base64 with padding.
+    pub fn url_safe(writer: W) -> Self {
+        Self(EncoderWriter::new(writer, &URL_SAFE))
+    }
+
+    /// Write the remaining encoded text, including padding, and return the underlying writer.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.0.finish()
+    }
+}
+
+impl<W> Write for Base64Writer<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.0.flush()
+    }
+}
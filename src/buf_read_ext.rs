@@ -0,0 +1,217 @@
+use std::fmt::{self, Debug, Formatter};
+use std::io::{BufRead, Error, ErrorKind, Result};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::fill_vec;
+
+/// Exact reads borrowing from the buffer of a [`BufRead`].
+pub trait BufReadExactExt: BufRead {
+    /// Read exactly `size` bytes, borrowing them from the internal buffer if possible.
+    ///
+    /// If the buffer returned by [`BufRead::fill_buf`] holds at least `size` bytes,
+    /// they are returned without copying and consumed once the returned [`BorrowedBytes`] is dropped.
+    /// Otherwise, the bytes are read into a `Vec<u8>`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`](std::io::Read::read_exact).
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::BufReadExactExt;
+    /// use std::io::{BufRead, BufReader};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut reader = BufReader::with_capacity(3, &bytes[..]);
+    ///
+    /// let borrowed = reader.read_bytes_borrowed(2).unwrap();
+    /// assert!(borrowed.is_borrowed());
+    /// assert_eq!(*borrowed, [0xAB, 0xCD]);
+    /// drop(borrowed);
+    ///
+    /// let owned = reader.read_bytes_borrowed(2).unwrap();
+    /// assert!(!owned.is_borrowed());
+    /// assert_eq!(*owned, [0xEF, 0x42]);
+    /// drop(owned);
+    ///
+    /// assert!(reader.read_bytes_borrowed(1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bytes_borrowed(&mut self, size: usize) -> Result<BorrowedBytes<'_, Self>> {
+        let reader: *mut Self = self;
+        // SAFETY: `reader` is derived from the exclusive borrow of `self`.
+        let buffer = unsafe { (*reader).fill_buf()? };
+
+        if buffer.len() >= size {
+            return Ok(BorrowedBytes {
+                inner: Inner::Buffered {
+                    reader,
+                    bytes: &raw const buffer[..size],
+                },
+                reader: PhantomData,
+            });
+        }
+
+        let mut owned = Vec::new();
+        fill_vec(self, &mut owned, size)?;
+        Ok(BorrowedBytes {
+            inner: Inner::Owned(owned),
+            reader: PhantomData,
+        })
+    }
+
+    /// Read a UTF-8 string of exactly `size` bytes, borrowing it from the internal buffer if possible.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the string is not valid UTF-8,
+    /// in which case its bytes are consumed nevertheless.
+    ///
+    /// For further semantics please refer to [`BufReadExactExt::read_bytes_borrowed`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::BufReadExactExt;
+    ///
+    /// let mut reader: &[u8] = b"hello\xFF";
+    /// assert_eq!(&*reader.read_str_borrowed(5).unwrap(), "hello");
+    /// assert!(reader.read_str_borrowed(1).is_err());
+    /// assert!(reader.is_empty());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_str_borrowed(&mut self, size: usize) -> Result<BorrowedStr<'_, Self>> {
+        let bytes = self.read_bytes_borrowed(size)?;
+
+        if let Err(error) = std::str::from_utf8(&bytes) {
+            return Err(Error::new(ErrorKind::InvalidData, error));
+        }
+
+        Ok(BorrowedStr { bytes })
+    }
+}
+
+impl<T> BufReadExactExt for T where T: BufRead {}
+
+/// Bytes read by [`BufReadExactExt::read_bytes_borrowed`].
+///
+/// The bytes are either borrowed from the buffer of the reader, which is consumed on drop,
+/// or owned if they did not fit into the buffer.
+pub struct BorrowedBytes<'a, R>
+where
+    R: BufRead + ?Sized,
+{
+    inner: Inner<R>,
+    reader: PhantomData<&'a mut R>,
+}
+
+enum Inner<R>
+where
+    R: ?Sized,
+{
+    Buffered { reader: *mut R, bytes: *const [u8] },
+    Owned(Vec<u8>),
+}
+
+impl<R> BorrowedBytes<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    /// Return `true` if the bytes are borrowed from the buffer of the reader.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self.inner, Inner::Buffered { .. })
+    }
+
+    /// Return the bytes as a `Vec<u8>`, consuming them from the reader.
+    #[must_use]
+    pub fn into_owned(mut self) -> Vec<u8> {
+        match &mut self.inner {
+            Inner::Buffered { .. } => self.to_vec(),
+            Inner::Owned(bytes) => std::mem::take(bytes),
+        }
+    }
+}
+
+impl<R> Deref for BorrowedBytes<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match &self.inner {
+            // SAFETY: The reader is exclusively borrowed for the lifetime of `self`
+            // and is not accessed before drop, so its buffer stays valid.
+            Inner::Buffered { bytes, .. } => unsafe { &**bytes },
+            Inner::Owned(bytes) => bytes,
+        }
+    }
+}
+
+impl<R> Debug for BorrowedBytes<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<R> Drop for BorrowedBytes<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    fn drop(&mut self) {
+        if let Inner::Buffered { reader, bytes } = self.inner {
+            // SAFETY: The reader is exclusively borrowed for the lifetime of `self`
+            // and the borrowed bytes are no longer used.
+            unsafe { (*reader).consume(bytes.len()) }
+        }
+    }
+}
+
+/// A string read by [`BufReadExactExt::read_str_borrowed`].
+///
+/// See [`BorrowedBytes`] for details.
+pub struct BorrowedStr<'a, R>
+where
+    R: BufRead + ?Sized,
+{
+    bytes: BorrowedBytes<'a, R>,
+}
+
+impl<R> BorrowedStr<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    /// Return `true` if the string is borrowed from the buffer of the reader.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        self.bytes.is_borrowed()
+    }
+
+    /// Return the string as a `String`, consuming it from the reader.
+    #[must_use]
+    pub fn into_owned(self) -> String {
+        // SAFETY: The bytes have been validated as UTF-8.
+        unsafe { String::from_utf8_unchecked(self.bytes.into_owned()) }
+    }
+}
+
+impl<R> Deref for BorrowedStr<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The bytes have been validated as UTF-8.
+        unsafe { std::str::from_utf8_unchecked(&self.bytes) }
+    }
+}
+
+impl<R> Debug for BorrowedStr<'_, R>
+where
+    R: BufRead + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
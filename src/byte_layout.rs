@@ -0,0 +1,133 @@
+/// Declare a struct together with [`ReadFrom`](crate::ReadFrom), [`WriteTo`](crate::WriteTo),
+/// [`FixedEncodedLen`](crate::FixedEncodedLen) and [`EncodedLen`](crate::EncodedLen) implementations
+/// that read and write its fields in declaration order.
+///
+/// Fields use the byte order passed to [`ReadFrom::read_from`](crate::ReadFrom::read_from) and
+/// [`WriteTo::write_to`](crate::WriteTo::write_to) unless it is overridden with
+/// `=> Big`, `=> Little` or `=> Native` after the field type.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{
+///     byte_layout, EncodedLen, Endianness, FixedEncodedLen, ReadValueExt, WriteValueExt,
+/// };
+/// use std::io::Cursor;
+///
+/// byte_layout! {
+///     /// A chunk header.
+///     #[derive(Debug, PartialEq)]
+///     pub struct Header {
+///         pub magic: [u8; 4],
+///         /// Size of the chunk, stored in little endian regardless of the container.
+///         pub size: u32 => Little,
+///         pub version: u16,
+///     }
+/// }
+///
+/// let bytes = [b'R', b'I', b'F', b'F', 0x24, 0x00, 0x00, 0x00, 0x00, 0x02];
+/// let header: Header = Cursor::new(&bytes).read_value(Endianness::Big).unwrap();
+/// assert_eq!(
+///     header,
+///     Header {
+///         magic: *b"RIFF",
+///         size: 0x24,
+///         version: 2,
+///     }
+/// );
+///
+/// let mut buffer = Vec::new();
+/// buffer.write_value(&header, Endianness::Big).unwrap();
+/// assert_eq!(buffer, bytes);
+/// assert_eq!(Header::ENCODED_LEN, bytes.len());
+/// assert_eq!(header.encoded_len(), bytes.len());
+/// ```
+#[macro_export]
+macro_rules! byte_layout {
+    (@endianness $default:ident) => {
+        $default
+    };
+    (@endianness $default:ident $endian:ident) => {
+        $crate::Endianness::$endian
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $type:ty $(=> $endian:ident)?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $type,
+            )*
+        }
+
+        impl $crate::ReadFrom for $name {
+            fn read_from<R>(
+                reader: &mut R,
+                endianness: $crate::Endianness,
+            ) -> ::std::io::Result<Self>
+            where
+                R: ::std::io::Read + ?Sized,
+            {
+                Ok(Self {
+                    $(
+                        $field: <$type as $crate::ReadFrom>::read_from(
+                            reader,
+                            $crate::byte_layout!(@endianness endianness $($endian)?),
+                        )?,
+                    )*
+                })
+            }
+
+            fn read_from_slice(
+                bytes: &mut &[u8],
+                endianness: $crate::Endianness,
+            ) -> ::std::io::Result<Self> {
+                Ok(Self {
+                    $(
+                        $field: <$type as $crate::ReadFrom>::read_from_slice(
+                            bytes,
+                            $crate::byte_layout!(@endianness endianness $($endian)?),
+                        )?,
+                    )*
+                })
+            }
+        }
+
+        impl $crate::WriteTo for $name {
+            fn write_to<W>(
+                &self,
+                writer: &mut W,
+                endianness: $crate::Endianness,
+            ) -> ::std::io::Result<()>
+            where
+                W: ::std::io::Write + ?Sized,
+            {
+                $(
+                    $crate::WriteTo::write_to(
+                        &self.$field,
+                        writer,
+                        $crate::byte_layout!(@endianness endianness $($endian)?),
+                    )?;
+                )*
+                Ok(())
+            }
+        }
+
+        impl $crate::FixedEncodedLen for $name {
+            const ENCODED_LEN: usize =
+                0 $(+ <$type as $crate::FixedEncodedLen>::ENCODED_LEN)*;
+        }
+
+        impl $crate::EncodedLen for $name {
+            fn encoded_len(&self) -> usize {
+                0 $(+ $crate::EncodedLen::encoded_len(&self.$field))*
+            }
+        }
+    };
+}
@@ -0,0 +1,222 @@
+use std::io::{BufRead, Error, ErrorKind, Read, Result};
+
+use crate::{Endianness, FromWireBytes, ReadFrom};
+
+/// A zero-copy reader over an in-memory byte slice.
+///
+/// Provides reading methods of [`ReadBytesExt`](crate::ReadBytesExt) and [`ReadNumExt`](crate::ReadNumExt),
+/// but returns data borrowed from the underlying slice where possible.
+/// Numbers, booleans, arrays, tuples and [`byte_layout!`](crate::byte_layout) structs are decoded
+/// directly from the slice through [`ReadFrom::read_from_slice`], without the indirection of a
+/// generic reader.
+/// Since `ByteReader` also implements [`Read`] and [`BufRead`],
+/// all other extension methods are available as well.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Create a new reader over the given bytes.
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Return the bytes that have not been read yet.
+    #[must_use]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Return the amount of bytes that have not been read yet.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Return `true` if all bytes have been read.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Borrow a byte array of a constant size.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut reader = ByteReader::new(&bytes);
+    /// assert_eq!(reader.read_array_exact::<3>().unwrap(), &[0xAB, 0xCD, 0xEF]);
+    /// assert_eq!(reader.remaining(), &[0x42]);
+    /// assert!(reader.read_array_exact::<2>().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_array_exact<const SIZE: usize>(&mut self) -> Result<&'a [u8; SIZE]> {
+        let (array, rest) = self.bytes.split_first_chunk().ok_or_else(eof)?;
+        self.bytes = rest;
+        Ok(array)
+    }
+
+    /// Read one byte and interpret it as a `bool`.
+    ///
+    /// Returns `true` if the read byte is non-zero, or `false` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[0x01, 0x00]);
+    /// assert!(reader.read_bool().unwrap());
+    /// assert!(!reader.read_bool().unwrap());
+    /// assert!(reader.read_bool().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_bool(&mut self) -> Result<bool> {
+        self.read_array_exact::<1>().map(|[byte]| *byte != 0)
+    }
+
+    /// Borrow a slice of a given size.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut reader = ByteReader::new(&bytes);
+    /// assert_eq!(reader.read_slice_exact(2).unwrap(), &[0xAB, 0xCD]);
+    /// assert!(reader.read_slice_exact(3).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_slice_exact(&mut self, size: usize) -> Result<&'a [u8]> {
+        if size > self.bytes.len() {
+            return Err(eof());
+        }
+
+        let (slice, rest) = self.bytes.split_at(size);
+        self.bytes = rest;
+        Ok(slice)
+    }
+
+    /// Borrow a UTF-8 string of a given size in bytes.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"Hello, world!");
+    /// assert_eq!(reader.read_str_exact(5).unwrap(), "Hello");
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_str_exact(&mut self, size: usize) -> Result<&'a str> {
+        let bytes = self.read_slice_exact(size)?;
+        std::str::from_utf8(bytes).map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a value of a type implementing [`ReadFrom`], decoding numbers in the given byte order.
+    ///
+    /// Unlike reading from a `&[u8]`, the reader is left unchanged if the value is incomplete.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ByteReader, Endianness};
+    ///
+    /// let mut reader = ByteReader::new(&[0x12, 0x34, 0x56, 0x78, 0x9A]);
+    /// let value: (u16, [u8; 2]) = reader.read_value(Endianness::Little).unwrap();
+    /// assert_eq!(value, (0x3412, [0x56, 0x78]));
+    /// assert!(reader.read_value::<u16>(Endianness::Little).is_err());
+    /// assert_eq!(reader.remaining(), &[0x9A]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_value<T>(&mut self, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        let mut bytes = self.bytes;
+        let value = T::read_from_slice(&mut bytes, endianness)?;
+        self.bytes = bytes;
+        Ok(value)
+    }
+
+    /// Read a number from a byte array in big endian.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let unsigned: u32 = ByteReader::new(&[0xAB, 0xCD, 0xEF, 0x42]).read_num_be().unwrap();
+    /// assert_eq!(unsigned, 0xABCDEF42);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_num_be<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(*bytes, Endianness::Big))
+    }
+
+    /// Read a number from a byte array in little endian.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ByteReader;
+    ///
+    /// let unsigned: u32 = ByteReader::new(&[0xAB, 0xCD, 0xEF, 0x42]).read_num_le().unwrap();
+    /// assert_eq!(unsigned, 0x42EFCDAB);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_num_le<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(*bytes, Endianness::Little))
+    }
+
+    /// Read a number from a byte array in native endianness.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_num_ne<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(*bytes, Endianness::Native))
+    }
+}
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.bytes.read(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        let bytes = self.read_slice_exact(buf.len())?;
+        buf.copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl BufRead for ByteReader<'_> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(self.bytes)
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.bytes = &self.bytes[amount.min(self.bytes.len())..];
+    }
+}
+
+impl<'a> From<&'a [u8]> for ByteReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+fn eof() -> Error {
+    Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer")
+}
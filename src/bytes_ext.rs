@@ -0,0 +1,1481 @@
+use std::io::{Error, ErrorKind, IoSlice, IoSliceMut, Read, Result, Seek, SeekFrom, Write};
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+use crate::{
+    check_size, fill, fill_or_eof, fill_vec, AlignedBuf, ChecksumKind, Endianness, ExactTake,
+    FixedEncodedLen, HexReader, HexWriter, Parser, ReadFrom, TlvFormat, Tlvs, TrailingBytes,
+    WriteTo,
+};
+#[cfg(feature = "flate2")]
+use crate::{CompressionFormat, Compressor, Decompressor};
+
+/// Amount of bytes transferred between two progress reports.
+const PROGRESS_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Initial amount of bytes by which buffers of untrusted sizes are grown while reading.
+#[cfg(feature = "allocator-api2")]
+const GROWTH_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Exact reads of raw bytes.
+///
+/// With the feature `bytes` enabled, the methods of this trait are available on `bytes::Buf`s
+/// through the [`Read`] implementor returned by `Buf::reader`.
+/// The trait is not implemented for `Buf`s directly.
+pub trait ReadBytesExt: Read {
+    /// Read a byte array of a constant size.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let array: [u8; 4] = Cursor::new(&bytes).read_array_exact().unwrap();
+    /// assert_eq!(array, bytes);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact<const SIZE: usize>(&mut self) -> Result<[u8; SIZE]> {
+        let mut buffer = [0; SIZE];
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read a `Vec<u8>` of a given size.
+    ///
+    /// The buffer is not zero-initialized before reading.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let vec = Cursor::new(&bytes).read_vec_exact(bytes.len()).unwrap();
+    /// assert_eq!(vec, Vec::from(bytes));
+    /// assert!(Cursor::new(&bytes).read_vec_exact(bytes.len() + 1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact(&mut self, size: usize) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(size);
+        fill_vec(self, &mut buffer, size)?;
+        Ok(buffer)
+    }
+
+    /// Read a `Vec<u8>` of a given size without aborting on allocation failure.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if `size` exceeds `max_size`,
+    /// or of kind [`ErrorKind::OutOfMemory`] if the buffer cannot be allocated.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    ///
+    /// let vec = Cursor::new(&bytes).try_read_vec_exact(4, Some(4)).unwrap();
+    /// assert_eq!(vec, Vec::from(bytes));
+    ///
+    /// let error = Cursor::new(&bytes).try_read_vec_exact(4, Some(3)).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    ///
+    /// let error = Cursor::new(&bytes).try_read_vec_exact(usize::MAX, None).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::OutOfMemory);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn try_read_vec_exact(&mut self, size: usize, max_size: Option<usize>) -> Result<Vec<u8>> {
+        check_size(size, max_size)?;
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(size)
+            .map_err(|error| Error::new(ErrorKind::OutOfMemory, error))?;
+        fill_vec(self, &mut buffer, size)?;
+        Ok(buffer)
+    }
+
+    /// Read a `Box<[u8]>` of a given size.
+    ///
+    /// The buffer is not zero-initialized before reading.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let boxed = Cursor::new(&bytes).read_boxed_slice_exact(bytes.len()).unwrap();
+    /// assert_eq!(&*boxed, &bytes);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_boxed_slice_exact(&mut self, size: usize) -> Result<Box<[u8]>> {
+        self.read_vec_exact(size).map(Vec::into_boxed_slice)
+    }
+
+    /// Read an `Arc<[u8]>` of a given size.
+    ///
+    /// The bytes are read like [`ReadBytesExt::read_vec_exact`] and then moved into the shared
+    /// allocation, so that no memory is allocated for bytes which are not available.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let arc = Cursor::new(&bytes).read_arc_slice_exact(bytes.len()).unwrap();
+    /// assert_eq!(&*arc, &bytes);
+    /// assert!(Cursor::new(&bytes).read_arc_slice_exact(usize::MAX).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_arc_slice_exact(&mut self, size: usize) -> Result<Arc<[u8]>> {
+        let mut buffer = Vec::new();
+        fill_vec(self, &mut buffer, size)?;
+        Ok(Arc::from(buffer))
+    }
+
+    /// Read a `bytes::Bytes` of a given size.
+    ///
+    /// The bytes are read into a [`bytes::BytesMut`], which is not zero-initialized before reading,
+    /// and then frozen, so that the result can be sliced and cloned without copying.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use bytes::Buf;
+    /// use rw_exact_ext::{ReadBytesExt, ReadNumExt};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut reader = (&bytes[..]).reader();
+    /// let head = reader.read_bytes_exact(2).unwrap();
+    /// assert_eq!(&head[..], &bytes[..2]);
+    /// assert_eq!(head.slice(1..), [0xCD][..]);
+    /// assert!(reader.read_bool().unwrap());
+    /// assert!(reader.read_bytes_exact(2).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "bytes")]
+    fn read_bytes_exact(&mut self, size: usize) -> Result<bytes::Bytes> {
+        use bytes::BufMut;
+
+        let mut buffer = bytes::BytesMut::with_capacity(size);
+        std::io::copy(
+            &mut Read::take(&mut *self, size as u64),
+            &mut (&mut buffer).writer(),
+        )?;
+
+        if buffer.len() < size {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+
+        Ok(buffer.freeze())
+    }
+
+    /// Read a `Vec<u8, A>` of a given size into the given allocator.
+    ///
+    /// The buffer grows in increasing chunks while reading, so an excessive `size`, for example
+    /// from an untrusted length prefix, does not allocate far more than the bytes available.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let vec = Cursor::new(&bytes).read_vec_exact_in(bytes.len(), Global).unwrap();
+    /// assert_eq!(vec.as_slice(), &bytes);
+    /// assert!(Cursor::new(&bytes).read_vec_exact_in(usize::MAX, Global).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "allocator-api2")]
+    fn read_vec_exact_in<A>(
+        &mut self,
+        size: usize,
+        alloc: A,
+    ) -> Result<allocator_api2::vec::Vec<u8, A>>
+    where
+        A: allocator_api2::alloc::Allocator,
+    {
+        let mut buffer = allocator_api2::vec::Vec::new_in(alloc);
+
+        while buffer.len() < size {
+            let start = buffer.len();
+            let chunk = (size - start).min(start.max(GROWTH_CHUNK_SIZE));
+            buffer.resize(start + chunk, 0);
+            self.read_exact(&mut buffer[start..])?;
+        }
+
+        Ok(buffer)
+    }
+
+    /// Read a `heapless::Vec<u8, SIZE>` of a given size.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds `SIZE`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let vec: heapless::Vec<u8, 4> = Cursor::new(&bytes).read_heapless_vec_exact(4).unwrap();
+    /// assert_eq!(&vec, &bytes);
+    /// assert!(Cursor::new(&bytes).read_heapless_vec_exact::<2>(3).is_err());
+    /// assert!(Cursor::new(&bytes).read_heapless_vec_exact::<8>(5).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "heapless")]
+    fn read_heapless_vec_exact<const SIZE: usize>(
+        &mut self,
+        size: usize,
+    ) -> Result<heapless::Vec<u8, SIZE>> {
+        let mut vec = heapless::Vec::<u8, SIZE>::new();
+        vec.resize(size, 0).map_err(|()| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("size {size} exceeds capacity {SIZE}"),
+            )
+        })?;
+        self.read_exact(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Read an `arrayvec::ArrayVec<u8, CAP>` of a given size.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds `CAP`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use arrayvec::ArrayVec;
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let vec: ArrayVec<u8, 8> = Cursor::new(&bytes).read_arrayvec_exact(3).unwrap();
+    /// assert_eq!(vec.as_slice(), &bytes[..3]);
+    /// assert!(Cursor::new(&bytes).read_arrayvec_exact::<2>(3).is_err());
+    /// assert!(Cursor::new(&bytes).read_arrayvec_exact::<8>(5).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "arrayvec")]
+    fn read_arrayvec_exact<const CAP: usize>(
+        &mut self,
+        size: usize,
+    ) -> Result<arrayvec::ArrayVec<u8, CAP>> {
+        if size > CAP {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("size {size} exceeds capacity {CAP}"),
+            ));
+        }
+
+        let mut vec = arrayvec::ArrayVec::from([0; CAP]);
+        vec.truncate(size);
+        self.read_exact(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Read a `generic_array::GenericArray<u8, N>` of the size given by the `typenum` number `N`.
+    ///
+    /// This is the counterpart of [`ReadBytesExt::read_array_exact`]
+    /// for generic code that cannot use `const SIZE: usize` parameters.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use generic_array::typenum::U3;
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let array = Cursor::new(&bytes).read_generic_array_exact::<U3>().unwrap();
+    /// assert_eq!(array.as_slice(), &bytes[..3]);
+    /// assert!(Cursor::new(&bytes[..2]).read_generic_array_exact::<U3>().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "generic-array")]
+    fn read_generic_array_exact<N>(&mut self) -> Result<generic_array::GenericArray<u8, N>>
+    where
+        N: generic_array::ArrayLength,
+    {
+        let mut array = generic_array::GenericArray::default();
+        self.read_exact(&mut array)?;
+        Ok(array)
+    }
+
+    /// Read a `smallvec::SmallVec<A>` of a given size.
+    ///
+    /// The vector spills onto the heap if `size` exceeds the inline capacity of `A`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use smallvec::SmallVec;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let vec: SmallVec<[u8; 2]> = Cursor::new(&bytes).read_smallvec_exact(2).unwrap();
+    /// assert!(!vec.spilled());
+    /// assert_eq!(vec.as_slice(), &bytes[..2]);
+    ///
+    /// let vec: SmallVec<[u8; 2]> = Cursor::new(&bytes).read_smallvec_exact(4).unwrap();
+    /// assert!(vec.spilled());
+    /// assert_eq!(vec.as_slice(), &bytes);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "smallvec")]
+    fn read_smallvec_exact<A>(&mut self, size: usize) -> Result<smallvec::SmallVec<A>>
+    where
+        A: smallvec::Array<Item = u8>,
+    {
+        let mut vec = smallvec::SmallVec::from_elem(0, size);
+        self.read_exact(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Read a COBS-encoded frame terminated by a zero byte and return the decoded payload.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the frame is malformed
+    /// or if the decoded payload exceeds `max_size`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x03, 0x11, 0x22, 0x02, 0x33, 0x00, 0x01, 0x00];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_cobs_frame(Some(4)).unwrap(), vec![0x11, 0x22, 0x00, 0x33]);
+    /// assert_eq!(cursor.read_cobs_frame(Some(4)).unwrap(), vec![]);
+    ///
+    /// assert!(Cursor::new(&bytes).read_cobs_frame(Some(3)).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_cobs_frame(&mut self, max_size: Option<usize>) -> Result<Vec<u8>> {
+        let mut frame = Vec::new();
+        let mut pending_zero = false;
+
+        loop {
+            let [code] = self.read_array_exact()?;
+
+            if code == 0 {
+                return Ok(frame);
+            }
+
+            let start = frame.len() + usize::from(pending_zero);
+            check_size(start + usize::from(code - 1), max_size)?;
+
+            if pending_zero {
+                frame.push(0);
+            }
+
+            frame.resize(start + usize::from(code - 1), 0);
+            self.read_exact(&mut frame[start..])?;
+
+            if frame[start..].contains(&0) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "unexpected zero byte in COBS frame",
+                ));
+            }
+
+            pending_zero = code != 0xFF;
+        }
+    }
+
+    /// Fill all of the given buffers completely, using [`Read::read_vectored`].
+    ///
+    /// `buffers` is advanced while reading, so its contents are unspecified afterwards.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::{Cursor, IoSliceMut};
+    ///
+    /// let bytes = [0x00, 0x02, 0xAB, 0xCD];
+    /// let mut header = [0; 2];
+    /// let mut payload = [0; 2];
+    /// Cursor::new(&bytes)
+    ///     .read_exact_vectored(&mut [IoSliceMut::new(&mut header), IoSliceMut::new(&mut payload)])
+    ///     .unwrap();
+    /// assert_eq!(header, [0x00, 0x02]);
+    /// assert_eq!(payload, [0xAB, 0xCD]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_vectored(&mut self, mut buffers: &mut [IoSliceMut<'_>]) -> Result<()> {
+        IoSliceMut::advance_slices(&mut buffers, 0);
+
+        while !buffers.is_empty() {
+            match self.read_vectored(buffers) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => IoSliceMut::advance_slices(&mut buffers, n),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read an array of a constant size, unless the stream has already ended.
+    ///
+    /// Returns `Ok(None)` if the end of the stream is reached before any byte was read,
+    /// and an error of kind [`ErrorKind::UnexpectedEof`] if it is reached after a part of the array.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_array_exact_or_eof::<2>().unwrap(), Some([0xAB, 0xCD]));
+    /// assert_eq!(cursor.read_array_exact_or_eof::<2>().unwrap(), Some([0xEF, 0x42]));
+    /// assert_eq!(cursor.read_array_exact_or_eof::<2>().unwrap(), None);
+    ///
+    /// let error = Cursor::new(&bytes).read_array_exact_or_eof::<8>().unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact_or_eof<const SIZE: usize>(&mut self) -> Result<Option<[u8; SIZE]>> {
+        let mut buffer = [0; SIZE];
+        Ok(fill_or_eof(self, &mut buffer)?.then_some(buffer))
+    }
+
+    /// Read a `Vec<u8>` of a given size, unless the stream has already ended.
+    ///
+    /// Returns `Ok(None)` if the end of the stream is reached before any byte was read,
+    /// and an error of kind [`ErrorKind::UnexpectedEof`] if it is reached after a part of the buffer.
+    /// The buffer grows while reading, so an excessive `size` does not allocate it up front.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_vec_exact_or_eof(2).unwrap(), Some(vec![0xAB, 0xCD]));
+    /// assert_eq!(
+    ///     cursor.read_vec_exact_or_eof(2).unwrap_err().kind(),
+    ///     ErrorKind::UnexpectedEof
+    /// );
+    /// assert_eq!(cursor.read_vec_exact_or_eof(2).unwrap(), None);
+    /// assert_eq!(cursor.read_vec_exact_or_eof(0).unwrap(), Some(Vec::new()));
+    /// assert!(Cursor::new(&bytes).read_vec_exact_or_eof(usize::MAX).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact_or_eof(&mut self, size: usize) -> Result<Option<Vec<u8>>> {
+        let mut buffer = Vec::new();
+        Read::take(&mut *self, size as u64).read_to_end(&mut buffer)?;
+
+        match buffer.len() {
+            0 if size > 0 => Ok(None),
+            len if len < size => Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            )),
+            _ => Ok(Some(buffer)),
+        }
+    }
+
+    /// Fill `buffer` like [`Read::read_exact`], but return the amount of bytes read instead of an
+    /// error if the end of the stream is reached early.
+    ///
+    /// Reads are retried on errors of kind [`ErrorKind::Interrupted`].
+    /// The returned amount is less than `buffer.len()` only if the stream has ended.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let mut buffer = [0; 2];
+    /// assert_eq!(cursor.read_fill(&mut buffer).unwrap(), 2);
+    /// assert_eq!(buffer, [0xAB, 0xCD]);
+    /// assert_eq!(cursor.read_fill(&mut buffer).unwrap(), 1);
+    /// assert_eq!(buffer[..1], [0xEF]);
+    /// assert_eq!(cursor.read_fill(&mut buffer).unwrap(), 0);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_fill(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        fill(self, buffer)
+    }
+
+    /// Read `magic.len()` bytes and verify that they equal `magic`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the read bytes differ.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = *b"RIFF\x24\x00\x00\x00";
+    /// let mut cursor = Cursor::new(&bytes);
+    /// cursor.read_magic(b"RIFF").unwrap();
+    /// assert_eq!(cursor.position(), 4);
+    ///
+    /// let error = Cursor::new(&bytes).read_magic(b"RIFX").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_magic(&mut self, magic: &[u8]) -> Result<()> {
+        let bytes = self.read_vec_exact(magic.len())?;
+
+        if bytes != magic {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid magic: expected {magic:02X?}, got {bytes:02X?}"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Clear `buffer` and fill it with exactly `size` bytes.
+    ///
+    /// The capacity of `buffer` is reused, so repeated reads into the same buffer
+    /// only allocate if a read exceeds all previous sizes.
+    /// On error, the contents of `buffer` are unspecified.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let mut buffer = Vec::with_capacity(4);
+    ///
+    /// cursor.read_exact_into(&mut buffer, 3).unwrap();
+    /// assert_eq!(buffer, [0xAB, 0xCD, 0xEF]);
+    ///
+    /// cursor.read_exact_into(&mut buffer, 1).unwrap();
+    /// assert_eq!(buffer, [0x42]);
+    /// assert!(buffer.capacity() >= 4);
+    ///
+    /// assert!(cursor.read_exact_into(&mut buffer, 1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_into(&mut self, buffer: &mut Vec<u8>, size: usize) -> Result<()> {
+        buffer.clear();
+        fill_vec(self, buffer, size)
+    }
+
+    /// Fill a possibly uninitialized buffer and return it as an initialized slice.
+    ///
+    /// When compiled with `--cfg rw_exact_ext_unstable` on a nightly toolchain, the buffer is
+    /// filled through `Read::read_buf_exact`, so readers which support uninitialized buffers,
+    /// such as files, sockets and slices, do not zero-initialize it.
+    /// Otherwise, since [`Read::read`] may inspect the buffer it is given, the buffer is
+    /// zero-initialized before reading, which keeps this method sound for arbitrary readers.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut scratch = [MaybeUninit::uninit(); 3];
+    /// let filled = Cursor::new(&bytes).read_exact_uninit(&mut scratch).unwrap();
+    /// assert_eq!(filled, [0xAB, 0xCD, 0xEF]);
+    ///
+    /// let mut scratch = [MaybeUninit::uninit(); 5];
+    /// assert!(Cursor::new(&bytes).read_exact_uninit(&mut scratch).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_uninit<'buf>(
+        &mut self,
+        buffer: &'buf mut [MaybeUninit<u8>],
+    ) -> Result<&'buf mut [u8]> {
+        #[cfg(rw_exact_ext_unstable)]
+        self.read_buf_exact(std::io::BorrowedBuf::from(&mut *buffer).unfilled())?;
+
+        #[cfg(not(rw_exact_ext_unstable))]
+        {
+            buffer.fill(MaybeUninit::new(0));
+            // SAFETY: Every element of `buffer` has been initialized above
+            // and `MaybeUninit<u8>` has the same layout as `u8`.
+            self.read_exact(unsafe {
+                std::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<u8>(), buffer.len())
+            })?;
+        }
+
+        // SAFETY: Every element of `buffer` has been initialized by the read above
+        // and `MaybeUninit<u8>` has the same layout as `u8`.
+        Ok(unsafe {
+            std::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<u8>(), buffer.len())
+        })
+    }
+
+    /// Fill a possibly uninitialized array and return it as an initialized array.
+    ///
+    /// Like [`ReadBytesExt::read_exact_uninit`], the array is only zero-initialized before reading
+    /// if the uninitialized read path is not available.
+    ///
+    /// For further semantics please refer to [`ReadBytesExt::read_exact_uninit`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut scratch = [MaybeUninit::uninit(); 4];
+    /// let filled: &mut [u8; 4] = Cursor::new(&bytes)
+    ///     .read_array_exact_uninit(&mut scratch)
+    ///     .unwrap();
+    /// assert_eq!(filled, &bytes);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact_uninit<'buf, const SIZE: usize>(
+        &mut self,
+        buffer: &'buf mut [MaybeUninit<u8>; SIZE],
+    ) -> Result<&'buf mut [u8; SIZE]> {
+        self.read_exact_uninit(buffer)?;
+        // SAFETY: `read_exact_uninit` has initialized every element of `buffer`
+        // and `MaybeUninit<u8>` has the same layout as `u8`.
+        Ok(unsafe { &mut *buffer.as_mut_ptr().cast::<[u8; SIZE]>() })
+    }
+
+    /// Read a `Vec<u8>` of a given size in chunks, reporting the progress after each chunk.
+    ///
+    /// `progress` is called with the total amount of bytes read so far.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = vec![0xAB; 100_000];
+    /// let mut reports = Vec::new();
+    /// let vec = Cursor::new(&bytes)
+    ///     .read_vec_exact_with_progress(bytes.len(), |done| reports.push(done))
+    ///     .unwrap();
+    /// assert_eq!(vec, bytes);
+    /// assert_eq!(reports, [65_536, 100_000]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact_with_progress<F>(&mut self, size: usize, mut progress: F) -> Result<Vec<u8>>
+    where
+        F: FnMut(usize),
+    {
+        let mut buffer = Vec::new();
+
+        while buffer.len() < size {
+            let chunk = PROGRESS_CHUNK_SIZE.min(size - buffer.len());
+            fill_vec(self, &mut buffer, chunk)?;
+            progress(buffer.len());
+        }
+
+        Ok(buffer)
+    }
+
+    /// Copy exactly `size` bytes to `writer`.
+    ///
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if the stream ends early.
+    ///
+    /// The bytes are copied with [`std::io::copy`], so on Linux copying between file descriptors
+    /// such as [`File`](std::fs::File)s, [`TcpStream`](std::net::TcpStream)s and pipes uses
+    /// `copy_file_range`, `sendfile` or `splice` to move the data in the kernel where possible,
+    /// falling back to a buffered loop otherwise.
+    /// The zero-copy path is only taken if the concrete types of the reader and writer are known,
+    /// i.e. not behind `dyn Read` or `dyn Write`.
+    ///
+    /// For further semantics please refer to [`std::io::copy`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut copy = Vec::new();
+    /// Cursor::new(&bytes).copy_exact(&mut copy, 3).unwrap();
+    /// assert_eq!(copy, [0xAB, 0xCD, 0xEF]);
+    /// assert!(Cursor::new(&bytes).copy_exact(&mut copy, 5).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn copy_exact<W>(&mut self, writer: &mut W, size: u64) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        if std::io::copy(&mut Read::take(&mut *self, size), writer)? < size {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Copy exactly `size` bytes to `writer` in chunks, reporting the progress after each chunk.
+    ///
+    /// `progress` is called with the total amount of bytes copied so far.
+    /// Each chunk is copied with [`ReadBytesExt::copy_exact`], so the same zero-copy paths apply.
+    ///
+    /// For further semantics please refer to [`ReadBytesExt::copy_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = vec![0xAB; 100_000];
+    /// let mut copy = Vec::new();
+    /// let mut reports = Vec::new();
+    /// Cursor::new(&bytes)
+    ///     .copy_exact_with_progress(&mut copy, 100_000, |done| reports.push(done))
+    ///     .unwrap();
+    /// assert_eq!(copy, bytes);
+    /// assert_eq!(reports, [65_536, 100_000]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn copy_exact_with_progress<W, F>(
+        &mut self,
+        writer: &mut W,
+        size: u64,
+        mut progress: F,
+    ) -> Result<()>
+    where
+        W: Write + ?Sized,
+        F: FnMut(u64),
+    {
+        let mut done = 0;
+
+        while done < size {
+            let chunk = (PROGRESS_CHUNK_SIZE as u64).min(size - done);
+            self.copy_exact(writer, chunk)?;
+            done += chunk;
+            progress(done);
+        }
+
+        Ok(())
+    }
+
+    /// Read a byte array of a constant size from hexadecimal text.
+    ///
+    /// Reads `2 * SIZE` hex digits, ignoring ASCII whitespace between them, as done by [`HexReader`].
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if a non-hex character is encountered.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new(b"deadBEEF rest");
+    /// assert_eq!(cursor.read_hex_array().unwrap(), [0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(cursor.position(), 8);
+    /// assert!(Cursor::new(b"xy").read_hex_array::<1>().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_hex_array<const SIZE: usize>(&mut self) -> Result<[u8; SIZE]> {
+        HexReader::new(self).read_array_exact()
+    }
+
+    /// Read a `Vec<u8>` of a given size followed by its 32-bit checksum and verify the checksum.
+    ///
+    /// The checksum is decoded in the given byte order.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] stating the expected and actual
+    /// checksum if they do not match.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ChecksumKind, Endianness, ReadBytesExt};
+    /// use std::io::Cursor;
+    ///
+    /// let frame = [b'a', b'b', b'c', 0x27, 0x01, 0x4D, 0x02];
+    /// let payload = Cursor::new(frame)
+    ///     .read_vec_checked(3, ChecksumKind::Adler32, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(payload, b"abc");
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_checked(
+        &mut self,
+        size: usize,
+        kind: ChecksumKind,
+        endianness: Endianness,
+    ) -> Result<Vec<u8>> {
+        let payload = self.read_vec_exact(size)?;
+        kind.verify(&payload, u32::read_from(self, endianness)?)?;
+        Ok(payload)
+    }
+
+    /// Read a type-length-value record in the given format and return its tag and value.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the format's field sizes are not
+    /// within `1..=8`, or of kind [`ErrorKind::InvalidData`] if the length exceeds `max_len`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadBytesExt, TlvFormat};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x09, 0x03, b'a', b'b', b'c'];
+    /// let (tag, value) = Cursor::new(&bytes).read_tlv(TlvFormat::U8, None).unwrap();
+    /// assert_eq!(tag, 0x09);
+    /// assert_eq!(value, b"abc");
+    /// assert!(Cursor::new(&bytes).read_tlv(TlvFormat::U8, Some(2)).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_tlv(&mut self, format: TlvFormat, max_len: Option<usize>) -> Result<(u64, Vec<u8>)> {
+        format
+            .read(self, max_len)?
+            .ok_or_else(|| Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"))
+    }
+
+    /// Return an iterator reading type-length-value records in the given format until the end of the stream.
+    ///
+    /// The iterator ends if the stream ends exactly after a record.
+    /// Any error, including the stream ending within a record, is yielded once and ends the iterator.
+    ///
+    /// For further semantics please refer to [`ReadBytesExt::read_tlv`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadBytesExt, TlvFormat};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0x01, 0x01, 0xAA, 0x02, 0x02, 0xBB];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let mut records = cursor.read_tlvs(TlvFormat::U8, None);
+    /// assert_eq!(records.next().unwrap().unwrap(), (0x01, vec![0xAA]));
+    /// assert_eq!(records.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    /// assert!(records.next().is_none());
+    /// ```
+    fn read_tlvs(&mut self, format: TlvFormat, max_len: Option<usize>) -> Tlvs<'_, Self> {
+        Tlvs::new(self, format, max_len)
+    }
+
+    /// Return a fluent [`Parser`] reading a sequence of values from the stream into a tuple.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = *b"GIF89a\x20\x00\x10\x00";
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let (width, height) = cursor.parse().tag(b"GIF89a")?.le::<u16>()?.le::<u16>()?.finish();
+    /// assert_eq!((width, height), (32, 16));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn parse(&mut self) -> Parser<'_, Self, ()> {
+        Parser::new(self)
+    }
+
+    /// Return a reader limited to exactly the next `size` bytes.
+    ///
+    /// Reading from it fails with [`ErrorKind::UnexpectedEof`] if the stream ends before `size` bytes.
+    /// Call [`ExactTake::finish`] to verify that all `size` bytes have been read,
+    /// or [`ExactTake::skip_rest`] to skip the remaining bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadBytesExt, ReadNumExt};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0x01, 0xAB, 0xCD, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let mut section = cursor.take_exact(3);
+    /// assert!(section.read_bool().unwrap());
+    /// assert_eq!(section.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+    ///
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let mut section = cursor.take_exact(3);
+    /// assert!(section.read_bool().unwrap());
+    /// section.skip_rest().unwrap();
+    /// assert_eq!(cursor.read_array_exact().unwrap(), [0x42]);
+    ///
+    /// let error = Cursor::new(&bytes).take_exact(5).read_vec_exact(5).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    /// ```
+    fn take_exact(&mut self, size: u64) -> ExactTake<'_, Self> {
+        ExactTake::new(self, size)
+    }
+
+    /// Read a length prefix as `P` in the given byte order and run `read` on a reader limited
+    /// to exactly that many following bytes.
+    ///
+    /// Bytes left unread by `read` are handled according to `trailing`.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the length does not fit into `u64`,
+    /// and of kind [`ErrorKind::UnexpectedEof`] if `read` reads beyond the end of the stream.
+    ///
+    /// For further semantics please refer to [`ReadBytesExt::take_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadBytesExt, ReadNumExt, TrailingBytes};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// // A message of 3 bytes, of which only the first one is known, followed by 0x42.
+    /// let bytes = [0x03, 0x01, 0xAB, 0xCD, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let flag = cursor
+    ///     .with_length_prefix::<u8, _, _>(Endianness::Big, TrailingBytes::Skip, |message| {
+    ///         message.read_bool()
+    ///     })
+    ///     .unwrap();
+    /// assert!(flag);
+    /// assert_eq!(cursor.read_array_exact().unwrap(), [0x42]);
+    ///
+    /// let error = Cursor::new(&bytes)
+    ///     .with_length_prefix::<u8, _, _>(Endianness::Big, TrailingBytes::Reject, |message| {
+    ///         message.read_bool()
+    ///     })
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn with_length_prefix<P, F, T>(
+        &mut self,
+        endianness: Endianness,
+        trailing: TrailingBytes,
+        read: F,
+    ) -> Result<T>
+    where
+        P: ReadFrom + TryInto<u64>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+        F: FnOnce(&mut ExactTake<'_, Self>) -> Result<T>,
+    {
+        let size = P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        let mut section = self.take_exact(size);
+        let value = read(&mut section)?;
+        section.finish_with(trailing)?;
+        Ok(value)
+    }
+
+    /// Read exactly `size` bytes into a new buffer aligned to `ALIGN` bytes.
+    ///
+    /// This is useful for readers that require aligned destination buffers,
+    /// such as files opened with `O_DIRECT`, which usually also require `size`
+    /// to be a multiple of the block size.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    /// let buffer = Cursor::new(&bytes).read_exact_aligned::<512>(3).unwrap();
+    /// assert_eq!(buffer.as_ptr() as usize % 512, 0);
+    /// assert_eq!(*buffer, [0xAB, 0xCD, 0xEF]);
+    /// assert!(Cursor::new(&bytes).read_exact_aligned::<512>(5).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_aligned<const ALIGN: usize>(&mut self, size: usize) -> Result<AlignedBuf<ALIGN>> {
+        let mut buffer = AlignedBuf::new(size);
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read a compressed frame prefixed with its compressed length as `P` in the given byte order,
+    /// and run `read` on the decompressed data.
+    ///
+    /// Decompressed data left unread by `read` is handled according to `trailing`.
+    /// The decompressor never reads beyond the end of the frame, and any remaining compressed
+    /// bytes of the frame are skipped afterwards.
+    ///
+    /// For further semantics please refer to [`ReadBytesExt::with_length_prefix`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{
+    ///     CompressionFormat, Endianness, ReadBytesExt, ReadNumExt, TrailingBytes, WriteBytesExt,
+    ///     WriteNumExt,
+    /// };
+    /// use std::io::Cursor;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes
+    ///     .write_frame_compressed::<u32, _, _>(Endianness::Big, CompressionFormat::Gzip, |frame| {
+    ///         frame.write_varint_u64(300)?;
+    ///         frame.write_bool(true)
+    ///     })
+    ///     .unwrap();
+    /// bytes.push(0x42);
+    ///
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let value = cursor
+    ///     .read_frame_compressed::<u32, _, _>(
+    ///         Endianness::Big,
+    ///         CompressionFormat::Gzip,
+    ///         TrailingBytes::Reject,
+    ///         |frame| Ok((frame.read_varint_u64()?, frame.read_bool()?)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(value, (300, true));
+    /// assert_eq!(cursor.read_array_exact().unwrap(), [0x42]);
+    /// ```
+    #[cfg(feature = "flate2")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_frame_compressed<P, F, T>(
+        &mut self,
+        endianness: Endianness,
+        format: CompressionFormat,
+        trailing: TrailingBytes,
+        read: F,
+    ) -> Result<T>
+    where
+        P: ReadFrom + TryInto<u64>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+        F: FnOnce(&mut Decompressor<ExactTake<'_, Self>>) -> Result<T>,
+    {
+        let size = P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        let mut decompressor = Decompressor::new(self.take_exact(size), format);
+        let value = read(&mut decompressor)?;
+
+        if trailing == TrailingBytes::Reject && decompressor.read(&mut [0])? > 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "trailing decompressed bytes not consumed",
+            ));
+        }
+
+        decompressor.into_inner().skip_rest()?;
+        Ok(value)
+    }
+
+    /// Fill an owned buffer completely and return it together with the result.
+    ///
+    /// This follows the convention of completion-based runtimes such as `tokio-uring`, `monoio`
+    /// or `glommio`, whose reads take ownership of a buffer and return it alongside the result.
+    /// Decoding logic written against this signature can therefore switch between blocking
+    /// readers and completion-based I/O without copying buffers.
+    /// The buffer is returned on error as well; its contents are unspecified in that case.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ByteReader, ReadBytesExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x13, 0x37, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    ///
+    /// let (result, buffer) = cursor.read_exact_owned([0; 2]);
+    /// result.unwrap();
+    /// let num: u16 = ByteReader::new(&buffer).read_num_be().unwrap();
+    /// assert_eq!(num, 0x1337);
+    ///
+    /// let (result, buffer) = cursor.read_exact_owned(buffer);
+    /// assert!(result.is_err());
+    /// assert_eq!(buffer.len(), 2);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_owned<B>(&mut self, mut buffer: B) -> (Result<()>, B)
+    where
+        B: AsMut<[u8]>,
+    {
+        let result = self.read_exact(buffer.as_mut());
+        (result, buffer)
+    }
+
+    /// Clear an owned `Vec<u8>`, fill it with exactly `size` bytes and return it together with the result.
+    ///
+    /// The capacity of `buffer` is reused, as with [`ReadBytesExt::read_exact_into`].
+    /// See [`ReadBytesExt::read_exact_owned`] for the ownership convention.
+    /// The buffer is returned on error as well; its contents are unspecified in that case.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadBytesExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF];
+    /// let mut cursor = Cursor::new(&bytes);
+    ///
+    /// let (result, buffer) = cursor.read_vec_exact_owned(Vec::with_capacity(4), 2);
+    /// result.unwrap();
+    /// assert_eq!(buffer, [0xAB, 0xCD]);
+    ///
+    /// let (result, buffer) = cursor.read_vec_exact_owned(buffer, 2);
+    /// assert!(result.is_err());
+    /// assert!(buffer.capacity() >= 4);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact_owned(&mut self, mut buffer: Vec<u8>, size: usize) -> (Result<()>, Vec<u8>) {
+        let result = self.read_exact_into(&mut buffer, size);
+        (result, buffer)
+    }
+}
+
+impl<T> ReadBytesExt for T where T: Read {}
+
+/// Writes of raw bytes.
+///
+/// With the feature `bytes` enabled, the methods of this trait are available on `bytes::BufMut`s
+/// through the [`Write`] implementor returned by `BufMut::writer`.
+/// The trait is not implemented for `BufMut`s directly.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "bytes")]
+/// # {
+/// use bytes::{BufMut, BytesMut};
+/// use rw_exact_ext::WriteBytesExt;
+///
+/// let mut writer = BytesMut::new().writer();
+/// writer.write_cobs_frame(&[0x11, 0x00]).unwrap();
+/// assert_eq!(&writer.into_inner()[..], [0x02, 0x11, 0x01, 0x00]);
+/// # }
+/// ```
+pub trait WriteBytesExt: Write {
+    /// Write `data` as a COBS-encoded frame terminated by a zero byte.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteBytesExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_cobs_frame(&[0x11, 0x22, 0x00, 0x33]).unwrap();
+    /// assert_eq!(bytes, [0x03, 0x11, 0x22, 0x02, 0x33, 0x00]);
+    /// ```
+    ///
+    /// Long runs of non-zero bytes are split into blocks of 254 bytes:
+    /// ```
+    /// use rw_exact_ext::{ReadBytesExt, WriteBytesExt};
+    /// use std::io::Cursor;
+    ///
+    /// for len in [254, 255] {
+    ///     let data = vec![0x42; len];
+    ///     let mut bytes = Vec::new();
+    ///     bytes.write_cobs_frame(&data).unwrap();
+    ///     assert_eq!(bytes.len(), len + 2 + len / 255);
+    ///     assert_eq!(bytes[0], 0xFF);
+    ///     assert_eq!(Cursor::new(&bytes).read_cobs_frame(None).unwrap(), data);
+    /// }
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_cobs_frame(&mut self, data: &[u8]) -> Result<()> {
+        let mut encoded = Vec::with_capacity(data.len() + data.len() / 254 + 2);
+        let mut code_index = 0;
+        let mut code = 1;
+        encoded.push(0);
+
+        for &byte in data {
+            if byte != 0 {
+                encoded.push(byte);
+                code += 1;
+            }
+
+            if byte == 0 || code == 0xFF {
+                encoded[code_index] = code;
+                code_index = encoded.len();
+                encoded.push(0);
+                code = 1;
+            }
+        }
+
+        // A run of 254 non-zero bytes at the end of the data needs no empty block after it.
+        if code == 1 && data.last().is_some_and(|&byte| byte != 0) {
+            encoded.pop();
+        } else {
+            encoded[code_index] = code;
+        }
+
+        encoded.push(0);
+        self.write_all(&encoded)
+    }
+
+    /// Write all bytes of the given slices, using [`Write::write_vectored`].
+    ///
+    /// `slices` is advanced while writing, so its contents are unspecified afterwards.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteBytesExt;
+    /// use std::io::IoSlice;
+    ///
+    /// let header = [0x00, 0x02];
+    /// let payload = [0xAB, 0xCD];
+    /// let mut bytes = Vec::new();
+    /// bytes
+    ///     .write_all_vectored_ext(&mut [IoSlice::new(&header), IoSlice::new(&payload)])
+    ///     .unwrap();
+    /// assert_eq!(bytes, [0x00, 0x02, 0xAB, 0xCD]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_all_vectored_ext(&mut self, mut slices: &mut [IoSlice<'_>]) -> Result<()> {
+        IoSlice::advance_slices(&mut slices, 0);
+
+        while !slices.is_empty() {
+            match self.write_vectored(slices) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => IoSlice::advance_slices(&mut slices, n),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write `data` as lowercase hexadecimal text.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteBytesExt;
+    ///
+    /// let mut text = Vec::new();
+    /// text.write_hex(&[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+    /// assert_eq!(text, b"deadbeef");
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_hex(&mut self, data: &[u8]) -> Result<()> {
+        HexWriter::new(self).write_all(data)
+    }
+
+    /// Write a byte slice followed by its 32-bit checksum.
+    ///
+    /// The checksum is encoded in the given byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ChecksumKind, Endianness, WriteBytesExt};
+    ///
+    /// let mut frame = Vec::new();
+    /// frame
+    ///     .write_all_checked(b"abc", ChecksumKind::Crc32, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(frame, [b'a', b'b', b'c', 0x35, 0x24, 0x41, 0xC2]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_all_checked(
+        &mut self,
+        bytes: &[u8],
+        kind: ChecksumKind,
+        endianness: Endianness,
+    ) -> Result<()> {
+        self.write_all(bytes)?;
+        kind.compute(bytes).write_to(self, endianness)
+    }
+
+    /// Write a type-length-value record in the given format.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the format's field sizes are not
+    /// within `1..=8`, or the tag or the length of `value` do not fit into their fields.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, TlvFormat, WriteBytesExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// let format = TlvFormat::new(1, 2, Endianness::Little);
+    /// bytes.write_tlv(format, 0x09, b"abc").unwrap();
+    /// assert_eq!(bytes, [0x09, 0x03, 0x00, b'a', b'b', b'c']);
+    /// assert!(bytes.write_tlv(format, 0x100, b"abc").is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_tlv(&mut self, format: TlvFormat, tag: u64, value: &[u8]) -> Result<()> {
+        format.write(self, tag, value)
+    }
+
+    /// Reserve a length prefix of type `P`, run `write`, and patch the prefix
+    /// with the amount of bytes written by `write` in the given byte order.
+    ///
+    /// The stream position after the call is the end of the written bytes.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the amount does not fit into `P`,
+    /// or if `write` leaves the stream positioned before the end of the prefix.
+    /// In that case, the reserved prefix remains zeroed.
+    ///
+    /// For further semantics please refer to [`Write::write_all`] and [`Seek::seek`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteBytesExt, WriteNumExt};
+    /// use std::io::{Cursor, Seek, Write};
+    ///
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// cursor.write_bool(true).unwrap();
+    /// cursor
+    ///     .write_with_length_prefix::<u16, _, _>(Endianness::Big, |message| {
+    ///         message.write_varint_u64(300)?;
+    ///         message.write_bool(false)
+    ///     })
+    ///     .unwrap();
+    /// cursor.write_bool(true).unwrap();
+    /// assert_eq!(cursor.into_inner(), [0x01, 0x00, 0x03, 0xAC, 0x02, 0x00, 0x01]);
+    ///
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// assert!(cursor
+    ///     .write_with_length_prefix::<u8, _, _>(Endianness::Big, |message| message.write_all(&[0; 256]))
+    ///     .is_err());
+    ///
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// assert!(cursor
+    ///     .write_with_length_prefix::<u8, _, _>(Endianness::Big, |message| message.rewind())
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_with_length_prefix<P, F, T>(&mut self, endianness: Endianness, write: F) -> Result<T>
+    where
+        Self: Seek,
+        P: WriteTo + FixedEncodedLen + TryFrom<u64>,
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let start = self.stream_position()?;
+        self.write_all(&vec![0; P::ENCODED_LEN])?;
+        let value = write(self)?;
+        let end = self.stream_position()?;
+        let len = end
+            .checked_sub(start)
+            .and_then(|len| len.checked_sub(P::ENCODED_LEN as u64))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    "stream position moved before the end of the length prefix",
+                )
+            })?;
+        let len = P::try_from(len).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "length does not fit into the length prefix",
+            )
+        })?;
+        self.seek(SeekFrom::Start(start))?;
+        len.write_to(self, endianness)?;
+        self.seek(SeekFrom::Start(end))?;
+        Ok(value)
+    }
+
+    /// Compress the data written by `write` and write it as a frame prefixed with its compressed
+    /// length as `P` in the given byte order.
+    ///
+    /// The frame is compressed into memory first and then written with [`Write::write_all`].
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the compressed length does not fit into `P`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// See [`ReadBytesExt::read_frame_compressed`] for an example.
+    #[cfg(feature = "flate2")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_frame_compressed<P, F, T>(
+        &mut self,
+        endianness: Endianness,
+        format: CompressionFormat,
+        write: F,
+    ) -> Result<T>
+    where
+        P: WriteTo + TryFrom<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+        F: FnOnce(&mut Compressor<Vec<u8>>) -> Result<T>,
+    {
+        let mut compressor = Compressor::new(Vec::new(), format);
+        let value = write(&mut compressor)?;
+        let frame = compressor.finish()?;
+        P::try_from(frame.len())
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+            .write_to(self, endianness)?;
+        self.write_all(&frame)?;
+        Ok(value)
+    }
+
+    /// Write all bytes of an owned buffer and return it together with the result.
+    ///
+    /// See [`ReadBytesExt::read_exact_owned`] for the ownership convention.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteBytesExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// let (result, buffer) = bytes.write_all_owned(vec![0x13, 0x37]);
+    /// result.unwrap();
+    /// assert_eq!(bytes, buffer);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_all_owned<B>(&mut self, buffer: B) -> (Result<()>, B)
+    where
+        B: AsRef<[u8]>,
+    {
+        let result = self.write_all(buffer.as_ref());
+        (result, buffer)
+    }
+}
+
+impl<T> WriteBytesExt for T where T: Write {}
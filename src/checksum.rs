@@ -0,0 +1,99 @@
+use std::io::{Error, ErrorKind, Result};
+
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+const CRC32_TABLE: [u32; 256] = crc32_table();
+const ADLER32_MODULUS: u32 = 65_521;
+const ADLER32_CHUNK_SIZE: usize = 5552;
+
+/// Checksum algorithms guarding a payload.
+///
+/// All checksums are 32 bits wide.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{ChecksumKind, Endianness, ReadBytesExt};
+/// use std::io::{Cursor, ErrorKind};
+///
+/// let frame = [b'a', b'b', b'c', 0x35, 0x24, 0x41, 0xC2];
+/// let payload = Cursor::new(frame)
+///     .read_vec_checked(3, ChecksumKind::Crc32, Endianness::Big)
+///     .unwrap();
+/// assert_eq!(payload, b"abc");
+///
+/// let error = Cursor::new(frame)
+///     .read_vec_checked(3, ChecksumKind::Adler32, Endianness::Big)
+///     .unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::InvalidData);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChecksumKind {
+    /// CRC-32 as used by Ethernet, gzip, PNG and zip.
+    Crc32,
+    /// Adler-32 as used by zlib.
+    Adler32,
+}
+
+impl ChecksumKind {
+    /// Return the checksum of `bytes`.
+    pub(crate) fn compute(self, bytes: &[u8]) -> u32 {
+        match self {
+            Self::Crc32 => !bytes.iter().fold(u32::MAX, |crc, &byte| {
+                CRC32_TABLE[usize::from(crc.to_le_bytes()[0] ^ byte)] ^ (crc >> 8)
+            }),
+            Self::Adler32 => {
+                let (a, b) =
+                    bytes
+                        .chunks(ADLER32_CHUNK_SIZE)
+                        .fold((1, 0), |(mut a, mut b), chunk| {
+                            for &byte in chunk {
+                                a += u32::from(byte);
+                                b += a;
+                            }
+                            (a % ADLER32_MODULUS, b % ADLER32_MODULUS)
+                        });
+                (b << 16) | a
+            }
+        }
+    }
+
+    /// Return an error of kind [`ErrorKind::InvalidData`] if `expected` is not the checksum of `bytes`.
+    pub(crate) fn verify(self, bytes: &[u8], expected: u32) -> Result<()> {
+        let actual = self.compute(bytes);
+
+        if actual != expected {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{self:?} checksum mismatch: expected {expected:#010x}, actual {actual:#010x}"
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
@@ -0,0 +1,102 @@
+use std::fmt::{self, Debug, Formatter};
+use std::io::{Error, Result};
+use std::marker::PhantomData;
+
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::push_decoder::decode_prefix;
+use crate::{EncodedLen, Endianness, ReadFrom, WriteTo};
+
+/// A [`Decoder`] and [`Encoder`] of values of a type implementing [`ReadFrom`], [`WriteTo`] and
+/// [`EncodedLen`], for use with `tokio_util::codec::Framed` and related transports.
+///
+/// Decoding follows the semantics of [`PushDecoder`](crate::PushDecoder): a value is decoded once
+/// the buffer contains all of its bytes, and incomplete values stay buffered until more bytes arrive.
+/// Encoding reserves [`EncodedLen::encoded_len`] bytes before writing a value.
+///
+/// # Examples
+/// ```
+/// use bytes::BytesMut;
+/// use rw_exact_ext::{Endianness, ValueCodec};
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// let mut codec = ValueCodec::<(u16, bool)>::new(Endianness::Big);
+/// let mut buffer = BytesMut::new();
+/// codec.encode((0x1337, true), &mut buffer).unwrap();
+/// assert_eq!(&buffer[..], [0x13, 0x37, 0x01]);
+///
+/// let mut partial = buffer.split_to(2);
+/// assert_eq!(codec.decode(&mut partial).unwrap(), None);
+/// partial.unsplit(buffer);
+/// assert_eq!(codec.decode(&mut partial).unwrap(), Some((0x1337, true)));
+/// assert!(partial.is_empty());
+/// ```
+pub struct ValueCodec<T> {
+    endianness: Endianness,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T> ValueCodec<T> {
+    /// Create a new codec, decoding and encoding numbers in the given byte order.
+    #[must_use]
+    pub const fn new(endianness: Endianness) -> Self {
+        Self {
+            endianness,
+            value: PhantomData,
+        }
+    }
+
+    /// Return the byte order of numbers.
+    #[must_use]
+    pub const fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+impl<T> Clone for ValueCodec<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ValueCodec<T> {}
+
+impl<T> Debug for ValueCodec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueCodec")
+            .field("endianness", &self.endianness)
+            .finish()
+    }
+}
+
+impl<T> Decoder for ValueCodec<T>
+where
+    T: ReadFrom,
+{
+    type Item = T;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        let endianness = self.endianness;
+
+        decode_prefix(src, |bytes| T::read_from(bytes, endianness)).map(|decoded| {
+            decoded.map(|(value, consumed)| {
+                src.advance(consumed);
+                value
+            })
+        })
+    }
+}
+
+impl<T> Encoder<T> for ValueCodec<T>
+where
+    T: WriteTo + EncodedLen,
+{
+    type Error = Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<()> {
+        dst.reserve(item.encoded_len());
+        item.write_to(&mut dst.writer(), self.endianness)
+    }
+}
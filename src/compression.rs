@@ -0,0 +1,158 @@
+use std::io::{Read, Result, Write};
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use flate2::Compression;
+
+/// Format of compressed data.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompressionFormat {
+    /// Raw DEFLATE data as specified in RFC 1951.
+    Deflate,
+    /// DEFLATE data in the zlib format as specified in RFC 1950.
+    Zlib,
+    /// DEFLATE data in the gzip format as specified in RFC 1952.
+    Gzip,
+}
+
+/// A reader that decompresses the data of an underlying reader.
+///
+/// All extension traits can be used on the decompressed data.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{CompressionFormat, Compressor, Decompressor, ReadNumExt, WriteNumExt};
+///
+/// let mut compressor = Compressor::new(Vec::new(), CompressionFormat::Zlib);
+/// compressor.write_varint_u64(300).unwrap();
+/// let compressed = compressor.finish().unwrap();
+///
+/// let mut decompressor = Decompressor::new(&compressed[..], CompressionFormat::Zlib);
+/// assert_eq!(decompressor.read_varint_u64().unwrap(), 300);
+/// ```
+#[derive(Debug)]
+pub struct Decompressor<R> {
+    decoder: Decoder<R>,
+}
+
+#[derive(Debug)]
+enum Decoder<R> {
+    Deflate(DeflateDecoder<R>),
+    Zlib(ZlibDecoder<R>),
+    Gzip(GzDecoder<R>),
+}
+
+impl<R> Decompressor<R>
+where
+    R: Read,
+{
+    /// Create a new decompressor reading data of the given format from `reader`.
+    pub fn new(reader: R, format: CompressionFormat) -> Self {
+        let decoder = match format {
+            CompressionFormat::Deflate => Decoder::Deflate(DeflateDecoder::new(reader)),
+            CompressionFormat::Zlib => Decoder::Zlib(ZlibDecoder::new(reader)),
+            CompressionFormat::Gzip => Decoder::Gzip(GzDecoder::new(reader)),
+        };
+        Self { decoder }
+    }
+
+    /// Return the underlying reader.
+    ///
+    /// Compressed data buffered by the decompressor is discarded.
+    pub fn into_inner(self) -> R {
+        match self.decoder {
+            Decoder::Deflate(decoder) => decoder.into_inner(),
+            Decoder::Zlib(decoder) => decoder.into_inner(),
+            Decoder::Gzip(decoder) => decoder.into_inner(),
+        }
+    }
+}
+
+impl<R> Read for Decompressor<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match &mut self.decoder {
+            Decoder::Deflate(decoder) => decoder.read(buf),
+            Decoder::Zlib(decoder) => decoder.read(buf),
+            Decoder::Gzip(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+/// A writer that compresses the data written to it into an underlying writer.
+///
+/// The compressed data is complete only after [`Compressor::finish`].
+///
+/// See [`Decompressor`] for an example.
+#[derive(Debug)]
+pub struct Compressor<W>
+where
+    W: Write,
+{
+    encoder: Encoder<W>,
+}
+
+#[derive(Debug)]
+enum Encoder<W>
+where
+    W: Write,
+{
+    Deflate(DeflateEncoder<W>),
+    Zlib(ZlibEncoder<W>),
+    Gzip(GzEncoder<W>),
+}
+
+impl<W> Compressor<W>
+where
+    W: Write,
+{
+    /// Create a new compressor writing data of the given format with the default level to `writer`.
+    pub fn new(writer: W, format: CompressionFormat) -> Self {
+        Self::with_level(writer, format, Compression::default())
+    }
+
+    /// Create a new compressor writing data of the given format with the given level to `writer`.
+    pub fn with_level(writer: W, format: CompressionFormat, level: Compression) -> Self {
+        let encoder = match format {
+            CompressionFormat::Deflate => Encoder::Deflate(DeflateEncoder::new(writer, level)),
+            CompressionFormat::Zlib => Encoder::Zlib(ZlibEncoder::new(writer, level)),
+            CompressionFormat::Gzip => Encoder::Gzip(GzEncoder::new(writer, level)),
+        };
+        Self { encoder }
+    }
+
+    /// Write the remaining compressed data and return the underlying writer.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying writer.
+    pub fn finish(self) -> Result<W> {
+        match self.encoder {
+            Encoder::Deflate(encoder) => encoder.finish(),
+            Encoder::Zlib(encoder) => encoder.finish(),
+            Encoder::Gzip(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W> Write for Compressor<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match &mut self.encoder {
+            Encoder::Deflate(encoder) => encoder.write(buf),
+            Encoder::Zlib(encoder) => encoder.write(buf),
+            Encoder::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match &mut self.encoder {
+            Encoder::Deflate(encoder) => encoder.flush(),
+            Encoder::Zlib(encoder) => encoder.flush(),
+            Encoder::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! `const fn` helpers to decode byte arrays at compile time.
+//!
+//! The helpers decode numbers the same way as [`ReadNumExt`](crate::ReadNumExt),
+//! so static lookup tables and headers embedded with `include_bytes!` can be parsed in
+//! `const` contexts.
+//! Instead of an error of kind [`ErrorKind::UnexpectedEof`](std::io::ErrorKind::UnexpectedEof),
+//! [`split_array`] returns `None` if there are not enough bytes left.
+//!
+//! # Examples
+//! ```
+//! use rw_exact_ext::const_parse::{parse_u16_be, parse_u32_le, split_array};
+//!
+//! const HEADER: &[u8] = &[0x00, 0x2A, 0x78, 0x56, 0x34, 0x12, 0xFF];
+//!
+//! const FIELDS: (u16, u32, &[u8]) = {
+//!     let Some((version, rest)) = split_array::<2>(HEADER) else {
+//!         panic!("header too short");
+//!     };
+//!     let Some((flags, rest)) = split_array::<4>(rest) else {
+//!         panic!("header too short");
+//!     };
+//!     (parse_u16_be(version), parse_u32_le(flags), rest)
+//! };
+//!
+//! assert_eq!(FIELDS, (42, 0x1234_5678, &[0xFF][..]));
+//! ```
+
+/// Split a byte array of a constant size off the front of `bytes`.
+///
+/// Returns `None` if `bytes` is shorter than `SIZE`.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::const_parse::split_array;
+///
+/// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+/// assert_eq!(split_array::<3>(&bytes), Some((&[0xAB, 0xCD, 0xEF], &[0x42][..])));
+/// assert_eq!(split_array::<5>(&bytes), None);
+/// ```
+#[must_use]
+pub const fn split_array<const SIZE: usize>(bytes: &[u8]) -> Option<(&[u8; SIZE], &[u8])> {
+    bytes.split_first_chunk()
+}
+
+/// Parse a boolean from a single byte, which is `true` unless it is zero.
+#[must_use]
+pub const fn parse_bool(bytes: &[u8; 1]) -> bool {
+    bytes[0] != 0
+}
+
+macro_rules! parse_num {
+    ($($type:ty => $be:ident, $le:ident;)*) => {
+        $(
+            #[doc = concat!("Parse a `", stringify!($type), "` from a byte array in big endian.")]
+            #[must_use]
+            pub const fn $be(bytes: &[u8; size_of::<$type>()]) -> $type {
+                <$type>::from_be_bytes(*bytes)
+            }
+
+            #[doc = concat!("Parse a `", stringify!($type), "` from a byte array in little endian.")]
+            #[must_use]
+            pub const fn $le(bytes: &[u8; size_of::<$type>()]) -> $type {
+                <$type>::from_le_bytes(*bytes)
+            }
+        )*
+    };
+}
+
+parse_num! {
+    u16 => parse_u16_be, parse_u16_le;
+    u32 => parse_u32_be, parse_u32_le;
+    u64 => parse_u64_be, parse_u64_le;
+    u128 => parse_u128_be, parse_u128_le;
+    i16 => parse_i16_be, parse_i16_le;
+    i32 => parse_i32_be, parse_i32_le;
+    i64 => parse_i64_be, parse_i64_le;
+    i128 => parse_i128_be, parse_i128_le;
+    f32 => parse_f32_be, parse_f32_le;
+    f64 => parse_f64_be, parse_f64_le;
+}
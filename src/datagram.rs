@@ -0,0 +1,258 @@
+use std::io::{Error, ErrorKind, Result};
+use std::net::UdpSocket;
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+
+use crate::{EncodedLen, Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+/// Maximum size of a datagram received by [`DatagramExt::recv_value`].
+/// Its buffer has one more byte, so that larger datagrams can be detected.
+const MAX_DATAGRAM_SIZE: usize = 64 * 1024;
+
+/// Typed exact reads and writes of whole datagrams on connected message-oriented sockets.
+///
+/// Each value is received from or sent as exactly one datagram.
+/// Receiving fails with an error of kind [`ErrorKind::InvalidData`] if the size of the datagram
+/// does not match the size of the value.
+///
+/// This trait is implemented for [`UdpSocket`] and, on Unix, [`UnixDatagram`].
+/// Other message-oriented sockets, such as `SOCK_SEQPACKET` sockets of third-party crates,
+/// only need to implement [`DatagramExt::recv_datagram`] and [`DatagramExt::send_datagram`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{DatagramExt, Endianness};
+/// use std::net::UdpSocket;
+///
+/// let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+/// let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+/// receiver.connect(sender.local_addr().unwrap()).unwrap();
+/// sender.connect(receiver.local_addr().unwrap()).unwrap();
+///
+/// sender.send_value(&(0x1234_u16, true), Endianness::Big).unwrap();
+/// let value: (u16, bool) = receiver.recv_value(Endianness::Big).unwrap();
+/// assert_eq!(value, (0x1234, true));
+///
+/// sender.send_datagram(&[0xAB, 0xCD, 0xEF]).unwrap();
+/// assert!(receiver.recv_array::<2>().is_err());
+/// ```
+pub trait DatagramExt {
+    /// Receive one datagram into `buffer` and return its size.
+    ///
+    /// Parts of the datagram which do not fit into `buffer` are discarded.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the socket.
+    fn recv_datagram(&self, buffer: &mut [u8]) -> Result<usize>;
+
+    /// Send `bytes` as one datagram and return the amount of bytes sent.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the socket.
+    fn send_datagram(&self, bytes: &[u8]) -> Result<usize>;
+
+    /// Receive one datagram of exactly `SIZE` bytes.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the datagram has a different size.
+    #[allow(clippy::missing_errors_doc)]
+    fn recv_array<const SIZE: usize>(&self) -> Result<[u8; SIZE]> {
+        let mut buffer = vec![0; SIZE + 1];
+        let size = self.recv_datagram(&mut buffer)?;
+
+        if size != SIZE {
+            return Err(datagram_size_mismatch(SIZE));
+        }
+
+        buffer.truncate(SIZE);
+        Ok(buffer
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly {SIZE} bytes should have been received")))
+    }
+
+    /// Receive one datagram and decode it as a value of a type implementing [`ReadFrom`].
+    ///
+    /// A buffer for the largest possible datagram is allocated on each call.
+    /// To avoid this, use [`DatagramExt::recv_value_with_buffer`] with a reused buffer,
+    /// or [`DatagramExt::recv_fixed_value`] for values of a fixed size.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the datagram is larger than
+    /// 64 KiB, is shorter than the value or contains trailing bytes after it.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(unix)]
+    /// # {
+    /// use rw_exact_ext::{DatagramExt, Endianness};
+    /// use std::os::unix::net::UnixDatagram;
+    ///
+    /// let (sender, receiver) = UnixDatagram::pair().unwrap();
+    ///
+    /// sender.send_datagram(&[0xAB; 64 * 1024]).unwrap();
+    /// let value: [u8; 64 * 1024] = receiver.recv_value(Endianness::Big).unwrap();
+    /// assert_eq!(value, [0xAB; 64 * 1024]);
+    ///
+    /// sender.send_datagram(&[0xAB; 64 * 1024 + 1]).unwrap();
+    /// assert!(receiver.recv_value::<[u8; 64 * 1024]>(Endianness::Big).is_err());
+    /// # }
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn recv_value<T>(&self, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        self.recv_value_with_buffer(&mut vec![0; MAX_DATAGRAM_SIZE + 1], endianness)
+    }
+
+    /// Receive one datagram into `buffer` and decode it as a value of a type implementing [`ReadFrom`].
+    ///
+    /// Since datagrams which do not fit into `buffer` are truncated, `buffer` must be
+    /// at least one byte larger than the datagram.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the datagram fills `buffer` completely,
+    /// is shorter than the value or contains trailing bytes after it.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{DatagramExt, Endianness};
+    /// use std::net::UdpSocket;
+    ///
+    /// let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    /// let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    /// receiver.connect(sender.local_addr().unwrap()).unwrap();
+    /// sender.connect(receiver.local_addr().unwrap()).unwrap();
+    ///
+    /// let mut buffer = [0; 16];
+    ///
+    /// sender.send_value(&[0x1234_u16; 2], Endianness::Big).unwrap();
+    /// let value: [u16; 2] = receiver.recv_value_with_buffer(&mut buffer, Endianness::Big).unwrap();
+    /// assert_eq!(value, [0x1234; 2]);
+    ///
+    /// sender.send_datagram(&[0; 16]).unwrap();
+    /// assert!(receiver.recv_value_with_buffer::<[u64; 2]>(&mut buffer, Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn recv_value_with_buffer<T>(&self, buffer: &mut [u8], endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        let size = self.recv_datagram(buffer)?;
+
+        if size == buffer.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "datagram does not fit into the buffer",
+            ));
+        }
+
+        decode_datagram(&buffer[..size], endianness)
+    }
+
+    /// Receive one datagram and decode it as a value of a type with a fixed encoded size.
+    ///
+    /// Only a buffer of the encoded size of the value is allocated.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the datagram has a different size.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{DatagramExt, Endianness};
+    /// use std::net::UdpSocket;
+    ///
+    /// let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    /// let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    /// receiver.connect(sender.local_addr().unwrap()).unwrap();
+    /// sender.connect(receiver.local_addr().unwrap()).unwrap();
+    ///
+    /// sender.send_value(&(0x1234_u16, true), Endianness::Little).unwrap();
+    /// let value: (u16, bool) = receiver.recv_fixed_value(Endianness::Little).unwrap();
+    /// assert_eq!(value, (0x1234, true));
+    ///
+    /// sender.send_datagram(&[0x34, 0x12, 0x01, 0x00]).unwrap();
+    /// assert!(receiver.recv_fixed_value::<(u16, bool)>(Endianness::Little).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn recv_fixed_value<T>(&self, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom + FixedEncodedLen,
+    {
+        let mut buffer = vec![0; T::ENCODED_LEN + 1];
+        let size = self.recv_datagram(&mut buffer)?;
+
+        if size != T::ENCODED_LEN {
+            return Err(datagram_size_mismatch(T::ENCODED_LEN));
+        }
+
+        decode_datagram(&buffer[..size], endianness)
+    }
+
+    /// Encode a value of a type implementing [`WriteTo`] and send it as one datagram.
+    ///
+    /// Returns an error of kind [`ErrorKind::WriteZero`] if the datagram was not sent completely.
+    #[allow(clippy::missing_errors_doc)]
+    fn send_value<T>(&self, value: &T, endianness: Endianness) -> Result<()>
+    where
+        T: WriteTo + EncodedLen + ?Sized,
+    {
+        let mut buffer = Vec::with_capacity(value.encoded_len());
+        value.write_to(&mut buffer, endianness)?;
+
+        if self.send_datagram(&buffer)? < buffer.len() {
+            return Err(Error::new(
+                ErrorKind::WriteZero,
+                "failed to send whole datagram",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl DatagramExt for UdpSocket {
+    fn recv_datagram(&self, buffer: &mut [u8]) -> Result<usize> {
+        self.recv(buffer)
+    }
+
+    fn send_datagram(&self, bytes: &[u8]) -> Result<usize> {
+        self.send(bytes)
+    }
+}
+
+#[cfg(unix)]
+impl DatagramExt for UnixDatagram {
+    fn recv_datagram(&self, buffer: &mut [u8]) -> Result<usize> {
+        self.recv(buffer)
+    }
+
+    fn send_datagram(&self, bytes: &[u8]) -> Result<usize> {
+        self.send(bytes)
+    }
+}
+
+/// Decode a value from a whole received datagram.
+fn decode_datagram<T>(mut datagram: &[u8], endianness: Endianness) -> Result<T>
+where
+    T: ReadFrom,
+{
+    let size = datagram.len();
+    let value = T::read_from(&mut datagram, endianness).map_err(|error| {
+        if error.kind() == ErrorKind::UnexpectedEof {
+            Error::new(ErrorKind::InvalidData, "datagram too short")
+        } else {
+            error
+        }
+    })?;
+
+    if !datagram.is_empty() {
+        return Err(datagram_size_mismatch(size - datagram.len()));
+    }
+
+    Ok(value)
+}
+
+/// Return an error of kind [`ErrorKind::InvalidData`] for a datagram of an unexpected size.
+fn datagram_size_mismatch(expected: usize) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("expected a datagram of {expected} bytes"),
+    )
+}
@@ -0,0 +1,135 @@
+use std::io::{Error, ErrorKind, Read, Result};
+use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::time::{Duration, Instant};
+
+/// Sources whose blocking reads can be limited by a timeout.
+pub trait ReadTimeout {
+    /// Return the current read timeout.
+    #[allow(clippy::missing_errors_doc)]
+    fn read_timeout(&self) -> Result<Option<Duration>>;
+
+    /// Set the read timeout.
+    #[allow(clippy::missing_errors_doc)]
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()>;
+}
+
+impl ReadTimeout for TcpStream {
+    fn read_timeout(&self) -> Result<Option<Duration>> {
+        Self::read_timeout(self)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        Self::set_read_timeout(self, timeout)
+    }
+}
+
+#[cfg(unix)]
+impl ReadTimeout for UnixStream {
+    fn read_timeout(&self) -> Result<Option<Duration>> {
+        Self::read_timeout(self)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        Self::set_read_timeout(self, timeout)
+    }
+}
+
+/// Exact reads that honor one overall deadline instead of a per-call timeout.
+///
+/// The read timeout of the source is adjusted before every underlying read
+/// and restored to its previous value afterwards.
+/// If the deadline passes before the read completes,
+/// an error of kind [`ErrorKind::TimedOut`] is returned.
+pub trait DeadlineReadExt: Read + ReadTimeout {
+    /// Fill the entire buffer before the deadline.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_deadline(&mut self, buffer: &mut [u8], deadline: Instant) -> Result<()> {
+        let previous = self.read_timeout()?;
+        let result = read_until(self, buffer, deadline);
+        self.set_read_timeout(previous)?;
+        result
+    }
+
+    /// Read a byte array of a constant size before the deadline.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::DeadlineReadExt;
+    /// use std::io::{ErrorKind, Write};
+    /// use std::net::{TcpListener, TcpStream};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    /// let (mut server, _) = listener.accept().unwrap();
+    /// server.write_all(&[0xAB, 0xCD, 0xEF, 0x42]).unwrap();
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    /// let array: [u8; 2] = client.read_array_exact_deadline(deadline).unwrap();
+    /// assert_eq!(array, [0xAB, 0xCD]);
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(100);
+    /// let error = client.read_array_exact_deadline::<4>(deadline).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::TimedOut);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact_deadline<const SIZE: usize>(
+        &mut self,
+        deadline: Instant,
+    ) -> Result<[u8; SIZE]> {
+        let mut buffer = [0; SIZE];
+        self.read_exact_deadline(&mut buffer, deadline)?;
+        Ok(buffer)
+    }
+
+    /// Read a `Vec<u8>` of a given size before the deadline.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact_deadline(&mut self, size: usize, deadline: Instant) -> Result<Vec<u8>> {
+        let mut buffer = vec![0; size];
+        self.read_exact_deadline(&mut buffer, deadline)?;
+        Ok(buffer)
+    }
+}
+
+impl<T> DeadlineReadExt for T where T: Read + ReadTimeout {}
+
+fn read_until<R>(reader: &mut R, mut buffer: &mut [u8], deadline: Instant) -> Result<()>
+where
+    R: Read + ReadTimeout + ?Sized,
+{
+    while !buffer.is_empty() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return Err(Error::new(ErrorKind::TimedOut, "deadline exceeded"));
+        }
+
+        reader.set_read_timeout(Some(remaining))?;
+
+        match reader.read(buffer) {
+            Ok(0) => {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                ))
+            }
+            Ok(n) => buffer = &mut buffer[n..],
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    ErrorKind::Interrupted | ErrorKind::WouldBlock | ErrorKind::TimedOut
+                ) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
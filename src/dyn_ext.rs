@@ -0,0 +1,290 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{check_size, Endianness, ReadBytesExt, ReadFrom, ReadNumExt, WriteNumExt, WriteTo};
+
+/// The type of a number selected at runtime.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NumKind {
+    /// [`u8`]
+    U8,
+    /// [`u16`]
+    U16,
+    /// [`u32`]
+    U32,
+    /// [`u64`]
+    U64,
+    /// [`u128`]
+    U128,
+    /// [`i8`]
+    I8,
+    /// [`i16`]
+    I16,
+    /// [`i32`]
+    I32,
+    /// [`i64`]
+    I64,
+    /// [`i128`]
+    I128,
+    /// [`f32`]
+    F32,
+    /// [`f64`]
+    F64,
+}
+
+impl NumKind {
+    /// Return the size of the number in bytes.
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::F64 => 8,
+            Self::U128 | Self::I128 => 16,
+        }
+    }
+}
+
+/// A number whose type is selected at runtime.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumValue {
+    /// A [`u8`].
+    U8(u8),
+    /// A [`u16`].
+    U16(u16),
+    /// A [`u32`].
+    U32(u32),
+    /// A [`u64`].
+    U64(u64),
+    /// A [`u128`].
+    U128(u128),
+    /// An [`i8`].
+    I8(i8),
+    /// An [`i16`].
+    I16(i16),
+    /// An [`i32`].
+    I32(i32),
+    /// An [`i64`].
+    I64(i64),
+    /// An [`i128`].
+    I128(i128),
+    /// An [`f32`].
+    F32(f32),
+    /// An [`f64`].
+    F64(f64),
+}
+
+impl NumValue {
+    /// Return the type of the number.
+    #[must_use]
+    pub const fn kind(self) -> NumKind {
+        match self {
+            Self::U8(_) => NumKind::U8,
+            Self::U16(_) => NumKind::U16,
+            Self::U32(_) => NumKind::U32,
+            Self::U64(_) => NumKind::U64,
+            Self::U128(_) => NumKind::U128,
+            Self::I8(_) => NumKind::I8,
+            Self::I16(_) => NumKind::I16,
+            Self::I32(_) => NumKind::I32,
+            Self::I64(_) => NumKind::I64,
+            Self::I128(_) => NumKind::I128,
+            Self::F32(_) => NumKind::F32,
+            Self::F64(_) => NumKind::F64,
+        }
+    }
+}
+
+/// Exact reads through dynamic dispatch.
+///
+/// Unlike the other extension traits, this trait has no generic methods, so it can be used as
+/// `dyn ReadDynExt`, and it is implemented for unsized readers such as `dyn Read`.
+/// Sizes and number types are selected at runtime instead,
+/// and byte arrays are read into a `&mut [u8]` by [`Read::read_exact`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, NumKind, NumValue, ReadDynExt};
+/// use std::io::{Cursor, Read};
+///
+/// let mut cursor = Cursor::new([0x12, 0x34, 0x01, b'h', b'i']);
+/// let reader: &mut dyn Read = &mut cursor;
+/// assert_eq!(
+///     reader.read_num_dyn(NumKind::U16, Endianness::Big).unwrap(),
+///     NumValue::U16(0x1234)
+/// );
+/// assert!(reader.read_bool_dyn().unwrap());
+/// assert_eq!(reader.read_string_dyn(2, None).unwrap(), "hi");
+///
+/// let mut plugin: Box<dyn ReadDynExt> = Box::new(Cursor::new([0xFF, 0xFF, 0xFF]));
+/// assert_eq!(plugin.read_int_dyn(3, Endianness::Little).unwrap(), -1);
+/// ```
+pub trait ReadDynExt: Read {
+    /// Read a boolean from a single byte, which is `true` unless it is zero.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bool_dyn(&mut self) -> Result<bool> {
+        bool::read_from(self, Endianness::Native)
+    }
+
+    /// Read a `Vec<u8>` of a given size.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if `size` exceeds `max_size`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_dyn(&mut self, size: usize, max_size: Option<usize>) -> Result<Vec<u8>> {
+        check_size(size, max_size)?;
+        let mut reader = self;
+        reader.read_vec_exact(size)
+    }
+
+    /// Read a UTF-8 string of a given size in bytes.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if `size` exceeds `max_size`
+    /// or the string is not valid UTF-8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_string_dyn(&mut self, size: usize, max_size: Option<usize>) -> Result<String> {
+        String::from_utf8(self.read_vec_dyn(size, max_size)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a number of the given type in the given byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_dyn(&mut self, kind: NumKind, endianness: Endianness) -> Result<NumValue> {
+        Ok(match kind {
+            NumKind::U8 => NumValue::U8(u8::read_from(self, endianness)?),
+            NumKind::U16 => NumValue::U16(u16::read_from(self, endianness)?),
+            NumKind::U32 => NumValue::U32(u32::read_from(self, endianness)?),
+            NumKind::U64 => NumValue::U64(u64::read_from(self, endianness)?),
+            NumKind::U128 => NumValue::U128(u128::read_from(self, endianness)?),
+            NumKind::I8 => NumValue::I8(i8::read_from(self, endianness)?),
+            NumKind::I16 => NumValue::I16(i16::read_from(self, endianness)?),
+            NumKind::I32 => NumValue::I32(i32::read_from(self, endianness)?),
+            NumKind::I64 => NumValue::I64(i64::read_from(self, endianness)?),
+            NumKind::I128 => NumValue::I128(i128::read_from(self, endianness)?),
+            NumKind::F32 => NumValue::F32(f32::read_from(self, endianness)?),
+            NumKind::F64 => NumValue::F64(f64::read_from(self, endianness)?),
+        })
+    }
+
+    /// Read an unsigned integer of `size` bytes in the given byte order.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_uint_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_uint_dyn(&mut self, size: usize, endianness: Endianness) -> Result<u64> {
+        let mut reader = self;
+
+        if endianness.is_big() {
+            reader.read_uint_be(size)
+        } else {
+            reader.read_uint_le(size)
+        }
+    }
+
+    /// Read a signed integer of `size` bytes in two's complement in the given byte order.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_int_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_int_dyn(&mut self, size: usize, endianness: Endianness) -> Result<i64> {
+        let mut reader = self;
+
+        if endianness.is_big() {
+            reader.read_int_be(size)
+        } else {
+            reader.read_int_le(size)
+        }
+    }
+}
+
+impl<T> ReadDynExt for T where T: Read + ?Sized {}
+
+/// Writes through dynamic dispatch.
+///
+/// This is the counterpart of [`ReadDynExt`] and can be used as `dyn WriteDynExt`.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, NumValue, WriteDynExt};
+/// use std::io::Write;
+///
+/// let mut bytes = Vec::new();
+/// let writer: &mut dyn Write = &mut bytes;
+/// writer.write_num_dyn(NumValue::U16(0x1234), Endianness::Little).unwrap();
+/// writer.write_uint_dyn(0x0A0B0C, 3, Endianness::Big).unwrap();
+/// writer.write_str_dyn("hi").unwrap();
+/// assert_eq!(bytes, [0x34, 0x12, 0x0A, 0x0B, 0x0C, b'h', b'i']);
+/// ```
+pub trait WriteDynExt: Write {
+    /// Write a boolean as a single byte.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bool_dyn(&mut self, boolean: bool) -> Result<()> {
+        boolean.write_to(self, Endianness::Native)
+    }
+
+    /// Write the bytes of a UTF-8 string.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_str_dyn(&mut self, string: &str) -> Result<()> {
+        self.write_all(string.as_bytes())
+    }
+
+    /// Write a number in the given byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_dyn(&mut self, num: NumValue, endianness: Endianness) -> Result<()> {
+        match num {
+            NumValue::U8(num) => num.write_to(self, endianness),
+            NumValue::U16(num) => num.write_to(self, endianness),
+            NumValue::U32(num) => num.write_to(self, endianness),
+            NumValue::U64(num) => num.write_to(self, endianness),
+            NumValue::U128(num) => num.write_to(self, endianness),
+            NumValue::I8(num) => num.write_to(self, endianness),
+            NumValue::I16(num) => num.write_to(self, endianness),
+            NumValue::I32(num) => num.write_to(self, endianness),
+            NumValue::I64(num) => num.write_to(self, endianness),
+            NumValue::I128(num) => num.write_to(self, endianness),
+            NumValue::F32(num) => num.write_to(self, endianness),
+            NumValue::F64(num) => num.write_to(self, endianness),
+        }
+    }
+
+    /// Write an unsigned integer as `size` bytes in the given byte order.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_uint_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_uint_dyn(&mut self, value: u64, size: usize, endianness: Endianness) -> Result<()> {
+        let mut writer = self;
+
+        if endianness.is_big() {
+            writer.write_uint_be(value, size)
+        } else {
+            writer.write_uint_le(value, size)
+        }
+    }
+
+    /// Write a signed integer in two's complement as `size` bytes in the given byte order.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_int_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_int_dyn(&mut self, value: i64, size: usize, endianness: Endianness) -> Result<()> {
+        let mut writer = self;
+
+        if endianness.is_big() {
+            writer.write_int_be(value, size)
+        } else {
+            writer.write_int_le(value, size)
+        }
+    }
+}
+
+impl<T> WriteDynExt for T where T: Write + ?Sized {}
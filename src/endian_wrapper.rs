@@ -0,0 +1,118 @@
+use std::io::{Read, Result, Write};
+
+use crate::{EncodedLen, Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+macro_rules! endian_wrapper {
+    ($(#[$attr:meta])* $wrapper:ident => $endianness:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        pub struct $wrapper<T>(pub T);
+
+        impl<T> $wrapper<T> {
+            /// Wrap a value.
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            /// Return the wrapped value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> From<T> for $wrapper<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T> ReadFrom for $wrapper<T>
+        where
+            T: ReadFrom,
+        {
+            fn read_from<R>(reader: &mut R, _: Endianness) -> Result<Self>
+            where
+                R: Read + ?Sized,
+            {
+                T::read_from(reader, Endianness::$endianness).map(Self)
+            }
+
+            fn read_from_slice(bytes: &mut &[u8], _: Endianness) -> Result<Self> {
+                T::read_from_slice(bytes, Endianness::$endianness).map(Self)
+            }
+        }
+
+        impl<T> WriteTo for $wrapper<T>
+        where
+            T: WriteTo,
+        {
+            fn write_to<W>(&self, writer: &mut W, _: Endianness) -> Result<()>
+            where
+                W: Write + ?Sized,
+            {
+                self.0.write_to(writer, Endianness::$endianness)
+            }
+        }
+
+        impl<T> FixedEncodedLen for $wrapper<T>
+        where
+            T: FixedEncodedLen,
+        {
+            const ENCODED_LEN: usize = T::ENCODED_LEN;
+        }
+
+        impl<T> EncodedLen for $wrapper<T>
+        where
+            T: EncodedLen,
+        {
+            fn encoded_len(&self) -> usize {
+                self.0.encoded_len()
+            }
+        }
+
+        endian_wrapper!(@into $wrapper: u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+    };
+    (@into $wrapper:ident: $($type:ty),*) => {
+        $(
+            impl From<$wrapper<$type>> for $type {
+                fn from(wrapper: $wrapper<$type>) -> Self {
+                    wrapper.0
+                }
+            }
+        )*
+    };
+}
+
+endian_wrapper!(
+    /// A value that is always read and written in big endian,
+    /// regardless of the byte order passed to [`ReadFrom::read_from`] and [`WriteTo::write_to`].
+    ///
+    /// This documents the wire byte order of a field in its type.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Be, Endianness, Le, ReadValueExt, WriteValueExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x12, 0x34, 0x78, 0x56];
+    /// let (big, little): (Be<u16>, Le<u16>) = Cursor::new(&bytes)
+    ///     .read_value(Endianness::Native)
+    ///     .unwrap();
+    /// assert_eq!(u16::from(big), 0x1234);
+    /// assert_eq!(little.into_inner(), 0x5678);
+    ///
+    /// let mut written = Vec::new();
+    /// written.write_value(&(big, little), Endianness::Native).unwrap();
+    /// assert_eq!(written, bytes);
+    /// ```
+    Be => Big
+);
+
+endian_wrapper!(
+    /// A value that is always read and written in little endian,
+    /// regardless of the byte order passed to [`ReadFrom::read_from`] and [`WriteTo::write_to`].
+    ///
+    /// See [`Be`] for an example.
+    Le => Little
+);
@@ -0,0 +1,60 @@
+/// Byte order of multi-byte values.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Endianness {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+    /// Byte order of the target platform.
+    Native,
+}
+
+impl Endianness {
+    /// Return whether values in this byte order match the byte order of the target platform.
+    pub(crate) const fn is_native(self) -> bool {
+        self.is_big() == cfg!(target_endian = "big")
+    }
+
+    /// Return whether values in this byte order are stored most significant byte first
+    /// on the target platform.
+    pub(crate) const fn is_big(self) -> bool {
+        match self {
+            Self::Big => true,
+            Self::Little => false,
+            Self::Native => cfg!(target_endian = "big"),
+        }
+    }
+}
+
+/// A byte order known at compile time.
+///
+/// This is implemented by the marker types [`BigEndian`], [`LittleEndian`] and [`NativeEndian`],
+/// which select the byte order of types such as `NumSlice` at compile time.
+pub trait ByteOrder {
+    /// The byte order as a runtime value.
+    const ENDIANNESS: Endianness;
+}
+
+/// Marker type for big endian.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BigEndian;
+
+impl ByteOrder for BigEndian {
+    const ENDIANNESS: Endianness = Endianness::Big;
+}
+
+/// Marker type for little endian.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LittleEndian;
+
+impl ByteOrder for LittleEndian {
+    const ENDIANNESS: Endianness = Endianness::Little;
+}
+
+/// Marker type for the byte order of the target platform.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NativeEndian;
+
+impl ByteOrder for NativeEndian {
+    const ENDIANNESS: Endianness = Endianness::Native;
+}
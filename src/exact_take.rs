@@ -0,0 +1,114 @@
+use std::io::{Error, ErrorKind, Read, Result};
+
+/// Handling of bytes left unread within a length-delimited section.
+///
+/// See [`ReadBytesExt::with_length_prefix`](crate::ReadBytesExt::with_length_prefix).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TrailingBytes {
+    /// Fail with an error of kind [`ErrorKind::InvalidData`].
+    #[default]
+    Reject,
+    /// Skip the remaining bytes.
+    Skip,
+}
+
+/// A reader limited to exactly a given amount of bytes of an underlying reader.
+///
+/// Unlike [`Read::take`], reading fails with [`ErrorKind::UnexpectedEof`] if the underlying
+/// reader ends before the limit is reached, and [`ExactTake::finish`] verifies that the limit
+/// has been consumed completely.
+///
+/// See [`ReadBytesExt::take_exact`](crate::ReadBytesExt::take_exact).
+#[derive(Debug)]
+pub struct ExactTake<'a, R>
+where
+    R: ?Sized,
+{
+    reader: &'a mut R,
+    remaining: u64,
+}
+
+impl<'a, R> ExactTake<'a, R>
+where
+    R: ?Sized,
+{
+    pub(crate) fn new(reader: &'a mut R, size: u64) -> Self {
+        Self {
+            reader,
+            remaining: size,
+        }
+    }
+
+    /// Return the amount of bytes that have not been read yet.
+    #[must_use]
+    pub const fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+impl<R> ExactTake<'_, R>
+where
+    R: Read + ?Sized,
+{
+    /// Verify that all bytes have been read.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if bytes remain.
+    pub fn finish(self) -> Result<()> {
+        if self.remaining > 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} trailing bytes not consumed", self.remaining),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Handle the bytes that have not been read yet according to `policy`.
+    ///
+    /// # Errors
+    /// Returns the errors of [`ExactTake::finish`] or [`ExactTake::skip_rest`] respectively.
+    pub fn finish_with(self, policy: TrailingBytes) -> Result<()> {
+        match policy {
+            TrailingBytes::Reject => self.finish(),
+            TrailingBytes::Skip => self.skip_rest(),
+        }
+    }
+
+    /// Skip all bytes that have not been read yet.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader,
+    /// or an error of kind [`ErrorKind::UnexpectedEof`] if it ends before the limit.
+    pub fn skip_rest(mut self) -> Result<()> {
+        std::io::copy(&mut self, &mut std::io::sink())?;
+        Ok(())
+    }
+}
+
+impl<R> Read for ExactTake<'_, R>
+where
+    R: Read + ?Sized,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if self.remaining == 0 || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let len = buf
+            .len()
+            .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+        let read = self.reader.read(&mut buf[..len])?;
+
+        if read == 0 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!("stream ended {} bytes before the limit", self.remaining),
+            ));
+        }
+
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
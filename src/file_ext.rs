@@ -0,0 +1,225 @@
+use std::io::Result;
+#[cfg(windows)]
+use std::io::{Error, ErrorKind};
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
+
+use crate::{AlignedBuf, Endianness, FromWireBytes, ToWireBytes};
+
+/// Offset-addressed exact reads and writes on files.
+///
+/// None of these methods use or modify the cursor of the file,
+/// so they can be used concurrently on a shared file.
+pub trait FileExactExt: FileExt {
+    /// Read a byte array of a constant size at the given offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::FileExactExt;
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("rw-exact-ext-read-array-exact-at");
+    /// std::fs::write(&path, [0xAB, 0xCD, 0xEF, 0x42]).unwrap();
+    /// let file = File::open(&path).unwrap();
+    /// assert_eq!(file.read_array_exact_at::<2>(1).unwrap(), [0xCD, 0xEF]);
+    /// assert!(file.read_array_exact_at::<2>(3).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact_at<const SIZE: usize>(&self, offset: u64) -> Result<[u8; SIZE]> {
+        let mut buffer = [0; SIZE];
+        read_exact_at(self, &mut buffer, offset)?;
+        Ok(buffer)
+    }
+
+    /// Read a `Vec<u8>` of a given size at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact_at(&self, size: usize, offset: u64) -> Result<Vec<u8>> {
+        let mut buffer = vec![0; size];
+        read_exact_at(self, &mut buffer, offset)?;
+        Ok(buffer)
+    }
+
+    /// Read exactly `size` bytes at the given offset into a new buffer aligned to `ALIGN` bytes.
+    ///
+    /// Files opened with `O_DIRECT` reject unaligned buffers and usually also require
+    /// `size` and `offset` to be multiples of the block size.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::FileExactExt;
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("rw-exact-ext-read-exact-aligned-at");
+    /// std::fs::write(&path, [0xAB, 0xCD, 0xEF, 0x42]).unwrap();
+    /// let file = File::open(&path).unwrap();
+    /// let buffer = file.read_exact_aligned_at::<4096>(2, 1).unwrap();
+    /// assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+    /// assert_eq!(*buffer, [0xCD, 0xEF]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_exact_aligned_at<const ALIGN: usize>(
+        &self,
+        size: usize,
+        offset: u64,
+    ) -> Result<AlignedBuf<ALIGN>> {
+        let mut buffer = AlignedBuf::new(size);
+        read_exact_at(self, &mut buffer, offset)?;
+        Ok(buffer)
+    }
+
+    /// Write all of `bytes` at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bytes_at(&self, bytes: &[u8], offset: u64) -> Result<()> {
+        write_all_at(self, bytes, offset)
+    }
+
+    /// Read a number from a byte array in big endian at the given offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::FileExactExt;
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("rw-exact-ext-read-num-be-at");
+    /// std::fs::write(&path, [0xAB, 0xCD, 0xEF, 0x42]).unwrap();
+    /// let file = File::open(&path).unwrap();
+    /// let num: u16 = file.read_num_be_at(2).unwrap();
+    /// assert_eq!(num, 0xEF42);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_be_at<N, const SIZE: usize>(&self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Big))
+    }
+
+    /// Read a number from a byte array in little endian at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_le_at<N, const SIZE: usize>(&self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Little))
+    }
+
+    /// Read a number from a byte array in native endianness at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_ne_at<N, const SIZE: usize>(&self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Native))
+    }
+
+    /// Write a number to bytes in big endian at the given offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::FileExactExt;
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("rw-exact-ext-write-num-be-at");
+    /// let file = File::create(&path).unwrap();
+    /// file.write_num_be_at(0x1337u16, 2).unwrap();
+    /// assert_eq!(std::fs::read(&path).unwrap(), [0x00, 0x00, 0x13, 0x37]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_be_at<N, const SIZE: usize>(&self, num: N, offset: u64) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        write_all_at(self, &num.to_wire_bytes(Endianness::Big), offset)
+    }
+
+    /// Write a number to bytes in little endian at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_le_at<N, const SIZE: usize>(&self, num: N, offset: u64) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        write_all_at(self, &num.to_wire_bytes(Endianness::Little), offset)
+    }
+
+    /// Write a number to bytes in native endianness at the given offset.
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_ne_at<N, const SIZE: usize>(&self, num: N, offset: u64) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        write_all_at(self, &num.to_wire_bytes(Endianness::Native), offset)
+    }
+}
+
+impl<T> FileExactExt for T where T: FileExt {}
+
+#[cfg(unix)]
+fn read_exact_at<F>(file: &F, buffer: &mut [u8], offset: u64) -> Result<()>
+where
+    F: FileExt + ?Sized,
+{
+    file.read_exact_at(buffer, offset)
+}
+
+#[cfg(unix)]
+fn write_all_at<F>(file: &F, bytes: &[u8], offset: u64) -> Result<()>
+where
+    F: FileExt + ?Sized,
+{
+    file.write_all_at(bytes, offset)
+}
+
+#[cfg(windows)]
+fn read_exact_at<F>(file: &F, mut buffer: &mut [u8], mut offset: u64) -> Result<()>
+where
+    F: FileExt + ?Sized,
+{
+    while !buffer.is_empty() {
+        match file.seek_read(buffer, offset) {
+            Ok(0) => {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                ))
+            }
+            Ok(n) => {
+                buffer = &mut buffer[n..];
+                offset += n as u64;
+            }
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn write_all_at<F>(file: &F, mut bytes: &[u8], mut offset: u64) -> Result<()>
+where
+    F: FileExt + ?Sized,
+{
+    while !bytes.is_empty() {
+        match file.seek_write(bytes, offset) {
+            Ok(0) => {
+                return Err(Error::new(
+                    ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => {
+                bytes = &bytes[n..];
+                offset += n as u64;
+            }
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::io::{Error, ErrorKind, Result};
+use std::num::FpCategory;
+
+/// Classes of floating-point values to reject when reading floats strictly.
+///
+/// The default policy accepts every value.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{FloatPolicy, ReadNumExt};
+/// use std::io::{Cursor, ErrorKind};
+///
+/// let nan = f32::NAN.to_be_bytes();
+/// assert!(Cursor::new(nan).read_f32_be_strict(FloatPolicy::default()).unwrap().is_nan());
+///
+/// let error = Cursor::new(nan).read_f32_be_strict(FloatPolicy::FINITE).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::InvalidData);
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FloatPolicy {
+    /// Reject NaN values.
+    pub reject_nan: bool,
+    /// Reject positive and negative infinity.
+    pub reject_infinite: bool,
+    /// Reject subnormal values.
+    pub reject_subnormal: bool,
+}
+
+impl FloatPolicy {
+    /// Accept finite values only.
+    pub const FINITE: Self = Self {
+        reject_nan: true,
+        reject_infinite: true,
+        reject_subnormal: false,
+    };
+
+    /// Accept zero and normal values only.
+    pub const NORMAL: Self = Self {
+        reject_nan: true,
+        reject_infinite: true,
+        reject_subnormal: true,
+    };
+
+    /// Return an error of kind [`ErrorKind::InvalidData`] if the category is rejected.
+    pub(crate) fn check(self, category: FpCategory) -> Result<()> {
+        let rejected = match category {
+            FpCategory::Nan => self.reject_nan.then_some("NaN"),
+            FpCategory::Infinite => self.reject_infinite.then_some("infinite"),
+            FpCategory::Subnormal => self.reject_subnormal.then_some("subnormal"),
+            FpCategory::Zero | FpCategory::Normal => None,
+        };
+
+        rejected.map_or(Ok(()), |class| {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{class} float rejected"),
+            ))
+        })
+    }
+}
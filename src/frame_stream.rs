@@ -0,0 +1,134 @@
+use std::io::{Error, ErrorKind, Result};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+
+use crate::{Endianness, PushDecoder, ReadFrom};
+
+/// Size of the chunks read from the underlying reader.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Asynchronous reads of typed values.
+pub trait AsyncReadValueExt: AsyncRead {
+    /// Turn the reader into a [`Stream`] of values of a type implementing [`ReadFrom`],
+    /// decoding numbers in the given byte order.
+    ///
+    /// See [`FrameStream`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures_core::Stream;
+    /// use rw_exact_ext::{AsyncReadValueExt, Endianness};
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let reader: &[u8] = &[0x00, 0x01, 0x00, 0x02];
+    /// let mut stream = reader.into_frame_stream::<u16>(Endianness::Big);
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert!(matches!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(Ok(1)))));
+    /// assert!(matches!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(Ok(2)))));
+    /// assert!(matches!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None)));
+    /// ```
+    fn into_frame_stream<T>(self, endianness: Endianness) -> FrameStream<Self, T>
+    where
+        Self: Sized + Unpin,
+        T: ReadFrom,
+    {
+        FrameStream::new(self, endianness)
+    }
+}
+
+impl<T> AsyncReadValueExt for T where T: AsyncRead {}
+
+/// A [`Stream`] decoding values from an asynchronous reader.
+///
+/// The bytes are read in chunks and buffered in a [`PushDecoder`], so values spanning
+/// several reads are decoded once they are complete, and no data is lost while the reader is pending.
+/// The stream ends if the reader ends exactly after a value.
+/// If the reader ends within a value, the stream yields an error of kind
+/// [`ErrorKind::UnexpectedEof`] and ends afterwards.
+#[derive(Debug)]
+pub struct FrameStream<R, T> {
+    reader: R,
+    decoder: PushDecoder,
+    done: bool,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<R, T> FrameStream<R, T> {
+    /// Create a new stream decoding values from `reader`, decoding numbers in the given byte order.
+    pub const fn new(reader: R, endianness: Endianness) -> Self {
+        Self {
+            reader,
+            decoder: PushDecoder::new(endianness),
+            done: false,
+            value: PhantomData,
+        }
+    }
+
+    /// Return the bytes read from the reader which have not been decoded yet.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        self.decoder.buffered()
+    }
+
+    /// Return the underlying reader, discarding any buffered bytes.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, T> Stream for FrameStream<R, T>
+where
+    R: AsyncRead + Unpin,
+    T: ReadFrom,
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut chunk = [0; CHUNK_SIZE];
+
+        loop {
+            match this.decoder.poll() {
+                Ok(Some(value)) => return Poll::Ready(Some(Ok(value))),
+                Ok(None) => {}
+                Err(error) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+            }
+
+            match Pin::new(&mut this.reader).poll_read(cx, &mut chunk) {
+                Poll::Ready(Ok(0)) => {
+                    this.done = true;
+
+                    if this.decoder.is_empty() {
+                        return Poll::Ready(None);
+                    }
+
+                    return Poll::Ready(Some(Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "stream ended within a value",
+                    ))));
+                }
+                Poll::Ready(Ok(n)) => this.decoder.push(&chunk[..n]),
+                Poll::Ready(Err(error)) if error.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(error)) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
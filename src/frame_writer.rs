@@ -0,0 +1,103 @@
+use std::io::{Result, Write};
+
+/// A writer that buffers a complete frame and writes it to the underlying writer
+/// with a single call to [`Write::write_all`] on [`FrameWriter::commit`].
+///
+/// Writes only append to the buffer, so other writers sharing the underlying writer never
+/// observe partially written frames. Uncommitted bytes are discarded by [`FrameWriter::abort`]
+/// and when the frame writer is dropped.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{FrameWriter, WriteNumExt};
+///
+/// let mut writer = FrameWriter::new(Vec::new());
+/// writer.write_bool(true).unwrap();
+/// writer.write_varint_u64(300).unwrap();
+/// assert!(writer.get_ref().is_empty());
+///
+/// writer.commit().unwrap();
+/// assert_eq!(writer.get_ref(), &[0x01, 0xAC, 0x02]);
+///
+/// writer.write_bool(false).unwrap();
+/// writer.abort();
+/// writer.commit().unwrap();
+/// assert_eq!(writer.into_inner(), [0x01, 0xAC, 0x02]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FrameWriter<W> {
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<W> FrameWriter<W> {
+    /// Create a new frame writer that commits frames to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Return the bytes of the current frame that have not been committed yet.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Discard the current frame.
+    pub fn abort(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Return a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Return the underlying writer, discarding the current frame.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> FrameWriter<W>
+where
+    W: Write,
+{
+    /// Write the current frame to the underlying writer with a single call to [`Write::write_all`].
+    ///
+    /// Empty frames are not written.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying writer.
+    /// The frame is kept in that case, but may have been written partially.
+    pub fn commit(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            self.writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+
+        Ok(())
+    }
+}
+
+impl<W> Write for FrameWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    /// Flush the underlying writer without committing the current frame.
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
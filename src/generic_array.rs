@@ -0,0 +1,76 @@
+//! [`ReadFrom`] and [`WriteTo`] implementations for the arrays of the `generic-array` crate.
+//!
+//! A `GenericArray<T, N>` is encoded like the array `[T; N]`, so generic code whose lengths are
+//! `typenum` numbers rather than `const SIZE: usize` parameters can use the same value traits.
+//!
+//! # Examples
+//! ```
+//! use generic_array::typenum::U2;
+//! use generic_array::GenericArray;
+//! use rw_exact_ext::{Endianness, ReadValueExt, WriteValueExt};
+//! use std::io::Cursor;
+//!
+//! let bytes = [0x12, 0x34, 0x56, 0x78];
+//! let array: GenericArray<u16, U2> = Cursor::new(&bytes).read_value(Endianness::Big).unwrap();
+//! assert_eq!(array.as_slice(), [0x1234, 0x5678]);
+//!
+//! let mut written = Vec::new();
+//! written.write_value(&array, Endianness::Little).unwrap();
+//! assert_eq!(written, [0x34, 0x12, 0x78, 0x56]);
+//! ```
+
+use std::io::{Read, Result, Write};
+
+use ::generic_array::{ArrayLength, GenericArray};
+
+use crate::{EncodedLen, Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+impl<T, N> ReadFrom for GenericArray<T, N>
+where
+    T: ReadFrom,
+    N: ArrayLength,
+{
+    fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+    where
+        R: Read + ?Sized,
+    {
+        let elements = (0..N::USIZE)
+            .map(|_| T::read_from(reader, endianness))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::try_from_iter(elements).unwrap_or_else(|_| {
+            unreachable!("exactly {} elements should have been read", N::USIZE)
+        }))
+    }
+}
+
+impl<T, N> WriteTo for GenericArray<T, N>
+where
+    T: WriteTo,
+    N: ArrayLength,
+{
+    fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        self.iter()
+            .try_for_each(|element| element.write_to(writer, endianness))
+    }
+}
+
+impl<T, N> FixedEncodedLen for GenericArray<T, N>
+where
+    T: FixedEncodedLen,
+    N: ArrayLength,
+{
+    const ENCODED_LEN: usize = T::ENCODED_LEN * N::USIZE;
+}
+
+impl<T, N> EncodedLen for GenericArray<T, N>
+where
+    T: EncodedLen,
+    N: ArrayLength,
+{
+    fn encoded_len(&self) -> usize {
+        self.iter().map(EncodedLen::encoded_len).sum()
+    }
+}
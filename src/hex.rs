@@ -0,0 +1,217 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+/// Amount of bytes encoded or decoded per call to the underlying writer or reader.
+const CHUNK_SIZE: usize = 256;
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// A reader that decodes hexadecimal text from the underlying reader.
+///
+/// Both lowercase and uppercase digits are accepted and ASCII whitespace between digits is ignored.
+/// Other characters result in an error of kind [`ErrorKind::InvalidData`].
+/// If bytes have been decoded before an invalid character within one read, they are returned
+/// first and the error is returned by the next read. Once an invalid character has been
+/// encountered, all further reads fail.
+/// The underlying reader is never read beyond the digits required to fill the buffer.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{HexReader, ReadBytesExt, ReadNumExt};
+///
+/// let text = b"abcd EF42\n1337";
+/// let mut reader = HexReader::new(&text[..]);
+/// assert_eq!(reader.read_array_exact().unwrap(), [0xAB, 0xCD, 0xEF, 0x42]);
+/// assert_eq!(reader.read_array_exact().unwrap(), [0x13, 0x37]);
+/// assert!(reader.read_bool().is_err());
+/// ```
+///
+/// Empty reads return `Ok(0)`, even while half of a byte is pending:
+/// ```
+/// use rw_exact_ext::HexReader;
+/// use std::io::Read;
+///
+/// let mut reader = HexReader::new(&b"ab c"[..]);
+/// let mut buffer = [0; 2];
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 1);
+/// assert_eq!(reader.read(&mut []).unwrap(), 0);
+/// ```
+///
+/// Bytes decoded before an invalid character are not lost:
+/// ```
+/// use rw_exact_ext::HexReader;
+/// use std::io::{ErrorKind, Read};
+///
+/// let mut reader = HexReader::new(&b"abcdxy"[..]);
+/// let mut buffer = [0; 3];
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(buffer[..2], [0xAB, 0xCD]);
+/// assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), ErrorKind::InvalidData);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HexReader<R> {
+    reader: R,
+    high_nibble: Option<u8>,
+    invalid_digit: Option<u8>,
+}
+
+impl<R> HexReader<R> {
+    /// Create a new hex reader decoding the text read from `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            high_nibble: None,
+            invalid_digit: None,
+        }
+    }
+
+    /// Return a reference to the underlying reader.
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Return a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> Read for HexReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if let Some(digit) = self.invalid_digit {
+            return Err(invalid_digit(digit));
+        }
+
+        let mut text = [0; 2 * CHUNK_SIZE];
+
+        loop {
+            let wanted = (2 * buf.len()).min(text.len()) - usize::from(self.high_nibble.is_some());
+
+            let read = self.reader.read(&mut text[..wanted])?;
+
+            if read == 0 {
+                return match self.high_nibble {
+                    Some(_) => Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "odd amount of hex digits",
+                    )),
+                    None => Ok(0),
+                };
+            }
+
+            let mut decoded = 0;
+
+            for &digit in text[..read]
+                .iter()
+                .filter(|digit| !digit.is_ascii_whitespace())
+            {
+                let nibble = match decode_digit(digit) {
+                    Ok(nibble) => nibble,
+                    Err(error) => {
+                        self.invalid_digit = Some(digit);
+                        return if decoded > 0 { Ok(decoded) } else { Err(error) };
+                    }
+                };
+
+                match self.high_nibble.take() {
+                    Some(high) => {
+                        buf[decoded] = (high << 4) | nibble;
+                        decoded += 1;
+                    }
+                    None => self.high_nibble = Some(nibble),
+                }
+            }
+
+            if decoded > 0 {
+                return Ok(decoded);
+            }
+        }
+    }
+}
+
+/// A writer that encodes the bytes written to it as lowercase hexadecimal text.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{HexWriter, WriteNumExt};
+/// use std::io::Write;
+///
+/// let mut writer = HexWriter::new(Vec::new());
+/// writer.write_bool(true).unwrap();
+/// writer.write_all(&[0xAB, 0xCD]).unwrap();
+/// assert_eq!(writer.into_inner(), b"01abcd");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HexWriter<W> {
+    writer: W,
+}
+
+impl<W> HexWriter<W> {
+    /// Create a new hex writer writing the encoded text to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Return a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> Write for HexWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let chunk = &buf[..buf.len().min(CHUNK_SIZE)];
+        let mut text = [0; 2 * CHUNK_SIZE];
+
+        for (byte, digits) in chunk.iter().zip(text.chunks_exact_mut(2)) {
+            digits[0] = DIGITS[usize::from(byte >> 4)];
+            digits[1] = DIGITS[usize::from(byte & 0x0F)];
+        }
+
+        self.writer.write_all(&text[..2 * chunk.len()])?;
+        Ok(chunk.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn decode_digit(digit: u8) -> Result<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(invalid_digit(digit)),
+    }
+}
+
+fn invalid_digit(digit: u8) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid hex digit: {:?}", char::from(digit)),
+    )
+}
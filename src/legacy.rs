@@ -0,0 +1,159 @@
+#![allow(deprecated)]
+
+use std::io::Result;
+
+use crate::{FromWireBytes, ReadBytesExt, ReadNumExt, ToWireBytes, WriteNumExt};
+
+/// Exact reads of byte arrays, vectors, booleans and numbers.
+///
+/// This is the trait of previous versions, which has been split into granular traits.
+/// Its methods delegate to [`ReadBytesExt`] and [`ReadNumExt`].
+///
+/// # Examples
+/// ```
+/// #![allow(deprecated)]
+/// use rw_exact_ext::ReadExactExt;
+/// use std::io::Cursor;
+///
+/// let mut cursor = Cursor::new([0x01, 0x13, 0x37]);
+/// assert!(cursor.read_bool().unwrap());
+/// assert_eq!(cursor.read_num_be::<u16, 2>().unwrap(), 0x1337);
+/// ```
+#[deprecated(note = "use `ReadBytesExt` and `ReadNumExt`, or `rw_exact_ext::prelude::*`")]
+pub trait ReadExactExt: ReadBytesExt + ReadNumExt {
+    /// Read a byte array of a constant size.
+    ///
+    /// See [`ReadBytesExt::read_array_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact<const SIZE: usize>(&mut self) -> Result<[u8; SIZE]> {
+        ReadBytesExt::read_array_exact(self)
+    }
+
+    /// Read one byte and interpret it as a `bool`.
+    ///
+    /// See [`ReadNumExt::read_bool`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bool(&mut self) -> Result<bool> {
+        ReadNumExt::read_bool(self)
+    }
+
+    /// Read a `Vec<u8>` of a given size.
+    ///
+    /// See [`ReadBytesExt::read_vec_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_exact(&mut self, size: usize) -> Result<Vec<u8>> {
+        ReadBytesExt::read_vec_exact(self, size)
+    }
+
+    /// Read a `heapless::Vec<u8>` of a constant size.
+    ///
+    /// See [`ReadBytesExt::read_heapless_vec_exact`].
+    ///
+    /// # Safety
+    /// This method is always safe to call. It is only declared `unsafe` for compatibility with
+    /// previous versions, in which its behavior was undefined if `size` > `SIZE`.
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "heapless")]
+    unsafe fn read_heapless_vec_exact<const SIZE: usize>(
+        &mut self,
+        size: usize,
+    ) -> Result<heapless::Vec<u8, SIZE>> {
+        ReadBytesExt::read_heapless_vec_exact(self, size)
+    }
+
+    /// Read a number from a byte array in big endian.
+    ///
+    /// See [`ReadNumExt::read_num_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_be<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        ReadNumExt::read_num_be(self)
+    }
+
+    /// Read a number from a byte array in little endian.
+    ///
+    /// See [`ReadNumExt::read_num_le`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_le<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        ReadNumExt::read_num_le(self)
+    }
+
+    /// Read a number from a byte array in native endianness.
+    ///
+    /// See [`ReadNumExt::read_num_ne`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_ne<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        ReadNumExt::read_num_ne(self)
+    }
+}
+
+impl<T> ReadExactExt for T where T: ReadBytesExt + ReadNumExt {}
+
+/// Exact writes of booleans and numbers.
+///
+/// This is the trait of previous versions, which has been split into granular traits.
+/// Its methods delegate to [`WriteNumExt`].
+///
+/// # Examples
+/// ```
+/// #![allow(deprecated)]
+/// use rw_exact_ext::WriteAllExt;
+///
+/// let mut bytes = Vec::new();
+/// bytes.write_bool(true).unwrap();
+/// bytes.write_num_be(0x1337_u16).unwrap();
+/// assert_eq!(bytes, [0x01, 0x13, 0x37]);
+/// ```
+#[deprecated(note = "use `WriteNumExt`, or `rw_exact_ext::prelude::*`")]
+pub trait WriteAllExt: WriteNumExt {
+    /// Write a `bool` as one byte.
+    ///
+    /// See [`WriteNumExt::write_bool`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bool(&mut self, boolean: bool) -> Result<()> {
+        WriteNumExt::write_bool(self, boolean)
+    }
+
+    /// Write a number to bytes in big endian.
+    ///
+    /// See [`WriteNumExt::write_num_be`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_be<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        WriteNumExt::write_num_be(self, num)
+    }
+
+    /// Write a number to bytes in little endian.
+    ///
+    /// See [`WriteNumExt::write_num_le`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_le<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        WriteNumExt::write_num_le(self, num)
+    }
+
+    /// Write a number to bytes in native endianness.
+    ///
+    /// See [`WriteNumExt::write_num_ne`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_ne<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        WriteNumExt::write_num_ne(self, num)
+    }
+}
+
+impl<T> WriteAllExt for T where T: WriteNumExt {}
@@ -1,273 +1,189 @@
-use std::io::{Read, Result, Write};
+#![cfg_attr(rw_exact_ext_unstable, feature(read_buf, core_io_borrowed_buf))]
 
-pub trait ReadExactExt: Read {
-    /// Read a byte array of a constant size.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
-    /// let array: [u8; 4] = Cursor::new(&bytes).read_array_exact().unwrap();
-    /// assert_eq!(array, bytes);
-    /// ```
-    #[allow(clippy::missing_errors_doc)]
-    fn read_array_exact<const SIZE: usize>(&mut self) -> Result<[u8; SIZE]> {
-        let mut buffer = [0; SIZE];
-        self.read_exact(&mut buffer)?;
-        Ok(buffer)
-    }
+use std::io::{Error, ErrorKind, Read, Result};
 
-    /// Read one byte and interpret it as a `bool`.
-    ///
-    /// Returns `true` if the read byte is non-zero, or `false` otherwise.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0x01, 0x00, 0xEF, 0x42];
-    /// let mut cursor = Cursor::new(&bytes);
-    /// assert!(cursor.read_bool().unwrap());
-    /// assert!(!cursor.read_bool().unwrap());
-    /// assert!(cursor.read_bool().unwrap());
-    /// assert!(cursor.read_bool().unwrap());
-    /// ```
-    #[allow(clippy::missing_errors_doc)]
-    fn read_bool(&mut self) -> Result<bool> {
-        self.read_array_exact::<1>().map(|[byte]| byte != 0)
-    }
+pub use aligned_buf::AlignedBuf;
+pub use atomic::AtomicInteger;
+#[cfg(feature = "base64")]
+pub use base64::{Base64Reader, Base64Writer};
+pub use buf_read_ext::{BorrowedBytes, BorrowedStr, BufReadExactExt};
+pub use byte_reader::ByteReader;
+pub use bytes_ext::{ReadBytesExt, WriteBytesExt};
+pub use checksum::ChecksumKind;
+#[cfg(feature = "tokio-util")]
+pub use codec::ValueCodec;
+#[cfg(feature = "flate2")]
+pub use compression::{CompressionFormat, Compressor, Decompressor};
+pub use datagram::DatagramExt;
+pub use deadline::{DeadlineReadExt, ReadTimeout};
+pub use dyn_ext::{NumKind, NumValue, ReadDynExt, WriteDynExt};
+pub use endian_wrapper::{Be, Le};
+pub use endianness::{BigEndian, ByteOrder, Endianness, LittleEndian, NativeEndian};
+pub use exact_take::{ExactTake, TrailingBytes};
+#[cfg(any(unix, windows))]
+pub use file_ext::FileExactExt;
+pub use float_policy::FloatPolicy;
+#[cfg(feature = "futures-io")]
+pub use frame_stream::{AsyncReadValueExt, FrameStream};
+pub use frame_writer::FrameWriter;
+pub use hex::{HexReader, HexWriter};
+#[allow(deprecated)]
+pub use legacy::{ReadExactExt, WriteAllExt};
+pub use mac_addr::MacAddr;
+pub use metered::{MeteredReader, MeteredWriter, TransferStats};
+#[cfg(feature = "test-util")]
+pub use mock::{FaultyWriter, MockReader};
+pub use net_ext::{ReadNetExt, WriteNetExt};
+pub use num_ext::{ReadNumExt, WriteNumExt};
+pub use num_slice::{NumSlice, NumSliceIter};
+pub use parser::{Append, Parser};
+pub use partial_read::PartialRead;
+pub use protobuf::WireType;
+pub use push_decoder::PushDecoder;
+pub use read_values::ReadValues;
+pub use record_file::{RecordFile, Records};
+pub use record_log::{RecordLog, RecordLogReader};
+pub use seek_ext::SeekReadExt;
+pub use slice_writer::{CapacityError, SliceWriter};
+pub use str_ext::{ReadStrExt, WriteStrExt};
+pub use tee::{TeeReader, TeeWriter};
+pub use time_ext::{ReadTimeExt, WriteTimeExt};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use timestamp::TimestampFormat;
+pub use tlv::{TlvFormat, Tlvs};
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub use traced::{TracedReader, TracedWriter};
+pub use transcode::transcode_nums;
+pub use utf8_reader::Utf8Reader;
+pub use value::{from_bytes_exact, EncodedLen, FixedEncodedLen, ReadFrom, WriteTo};
+pub use value_ext::{ReadValueExt, WriteValueExt};
+pub use wire_bytes::{FromWireBytes, ToWireBytes};
 
-    /// Read a `Vec<u8>` of a given size.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
-    /// let vec = Cursor::new(&bytes).read_vec_exact(bytes.len()).unwrap();
-    /// assert_eq!(vec, Vec::from(bytes));
-    /// ```
-    #[allow(clippy::missing_errors_doc)]
-    fn read_vec_exact(&mut self, size: usize) -> Result<Vec<u8>> {
-        let mut buffer = vec![0; size];
-        self.read_exact(&mut buffer)?;
-        Ok(buffer)
-    }
+mod aligned_buf;
+#[cfg(feature = "arbitrary-int")]
+mod arbitrary_int;
+mod atomic;
+#[cfg(feature = "base64")]
+mod base64;
+mod buf_read_ext;
+mod byte_layout;
+mod byte_reader;
+mod bytes_ext;
+mod checksum;
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(feature = "flate2")]
+mod compression;
+pub mod const_parse;
+mod datagram;
+mod deadline;
+mod dyn_ext;
+mod endian_wrapper;
+mod endianness;
+mod exact_take;
+#[cfg(any(unix, windows))]
+mod file_ext;
+mod float_policy;
+#[cfg(feature = "futures-io")]
+mod frame_stream;
+mod frame_writer;
+#[cfg(feature = "generic-array")]
+mod generic_array;
+mod hex;
+mod legacy;
+mod mac_addr;
+mod metered;
+#[cfg(feature = "test-util")]
+mod mock;
+mod net_ext;
+mod num_ext;
+mod num_slice;
+mod os_string;
+mod parser;
+mod partial_read;
+pub mod prelude;
+mod protobuf;
+mod push_decoder;
+mod read_values;
+mod record_file;
+mod record_log;
+pub mod schema;
+mod seek_ext;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod slice_writer;
+mod str_ext;
+mod tee;
+mod time_ext;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod timestamp;
+mod tlv;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod traced;
+mod transcode;
+mod utf8_reader;
+mod value;
+mod value_ext;
+mod wire_bytes;
 
-    /// Read a `heapless::Vec<u8>` of a constant size.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Safety
-    /// Behavior of this method is undefined, if `size` > `SIZE`.
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
-    /// let vec: heapless::Vec<u8, 4> = unsafe { Cursor::new(&bytes).read_heapless_vec_exact(4).unwrap() };
-    /// eprintln!("Capacity: {}", vec.capacity());
-    /// eprintln!("Len: {}", vec.len());
-    /// assert_eq!(&vec, &bytes);
-    /// ```
-    #[allow(clippy::missing_errors_doc)]
-    #[cfg(feature = "heapless")]
-    unsafe fn read_heapless_vec_exact<const SIZE: usize>(
-        &mut self,
-        size: usize,
-    ) -> Result<heapless::Vec<u8, SIZE>> {
-        let mut vec = heapless::Vec::<u8, SIZE>::new();
-        vec.set_len(size);
-        self.read_exact(&mut vec)?;
-        Ok(vec)
+/// Return an error of kind [`ErrorKind::InvalidData`] if `size` exceeds `max_size`.
+fn check_size(size: usize, max_size: Option<usize>) -> Result<()> {
+    if max_size.is_some_and(|max_size| size > max_size) {
+        return Err(Error::new(ErrorKind::InvalidData, "size exceeds maximum"));
     }
 
-    /// Read a number from a byte array in big endian.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
-    ///
-    /// let unsigned: u32 = Cursor::new(&bytes).read_num_be().unwrap();
-    /// assert_eq!(unsigned, 0xABCDEF42);
-    ///
-    /// let signed: i32 = Cursor::new(&bytes).read_num_be().unwrap();
-    /// assert_eq!(signed, -0x543210BE);
-    ///
-    /// let float: f32 = Cursor::new(&bytes).read_num_be().unwrap();
-    /// assert_eq!(float, -1.4632533e-12);
-    /// ```
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn read_num_be<N, const SIZE: usize>(&mut self) -> Result<N>
-    where
-        N: num_traits::FromBytes<Bytes = [u8; SIZE]>,
-    {
-        self.read_array_exact()
-            .map(|bytes| N::from_be_bytes(&bytes))
-    }
+    Ok(())
+}
 
-    /// Read a number from a byte array in little endian.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::ReadExactExt;
-    /// use std::io::Cursor;
-    ///
-    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
-    ///
-    /// let unsigned: u32 = Cursor::new(&bytes).read_num_le().unwrap();
-    /// assert_eq!(unsigned, 0x42EFCDAB);
-    ///
-    /// let signed: i32 = Cursor::new(&bytes).read_num_le().unwrap();
-    /// assert_eq!(signed, 0x42EFCDAB);
-    ///
-    /// let float: f32 = Cursor::new(&bytes).read_num_le().unwrap();
-    /// assert_eq!(float, 119.901695);
-    /// ```
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn read_num_le<N, const SIZE: usize>(&mut self) -> Result<N>
-    where
-        N: num_traits::FromBytes<Bytes = [u8; SIZE]>,
-    {
-        self.read_array_exact()
-            .map(|bytes| N::from_le_bytes(&bytes))
-    }
+/// Append exactly `size` bytes from `reader` to `buffer` without zero-filling them first.
+fn fill_vec<R>(reader: &mut R, buffer: &mut Vec<u8>, size: usize) -> Result<()>
+where
+    R: Read + ?Sized,
+{
+    let start = buffer.len();
+    reader.take(size as u64).read_to_end(buffer)?;
 
-    /// Read a number from a byte array in native endianness.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn read_num_ne<N, const SIZE: usize>(&mut self) -> Result<N>
-    where
-        N: num_traits::FromBytes<Bytes = [u8; SIZE]>,
-    {
-        self.read_array_exact()
-            .map(|bytes| N::from_ne_bytes(&bytes))
+    if buffer.len() - start < size {
+        return Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ));
     }
-}
 
-impl<T> ReadExactExt for T where T: Read {}
+    Ok(())
+}
 
-pub trait WriteAllExt: Write {
-    /// Write a `bool` as one byte.
-    ///
-    /// For further semantics please refer to [`Read::read_exact`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::WriteAllExt;
-    /// use std::io::Cursor;
-    ///
-    /// let mut bytes = vec![0xFF];
-    ///
-    /// Cursor::new(&mut bytes).write_bool(true).unwrap();
-    /// assert_eq!(bytes, [0x01]);
-    ///
-    /// Cursor::new(&mut bytes).write_bool(false).unwrap();
-    /// assert_eq!(bytes, [0x00]);
-    /// ```
-    #[allow(clippy::missing_errors_doc)]
-    fn write_bool(&mut self, boolean: bool) -> Result<()> {
-        self.write_all(&[boolean.into()])
-    }
+/// Fill `buffer` as far as possible and return the amount of bytes read, which is less than
+/// `buffer.len()` only if the stream has ended.
+fn fill<R>(reader: &mut R, buffer: &mut [u8]) -> Result<usize>
+where
+    R: Read + ?Sized,
+{
+    let mut filled = 0;
 
-    /// Write a number to bytes in big endian.
-    ///
-    /// For further semantics please refer to [`Write::write_all`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::WriteAllExt;
-    /// use std::io::Cursor;
-    ///
-    /// let mut bytes = vec![0u8; 4];
-    ///
-    /// let unsigned: u32 = 1337;
-    /// Cursor::new(&mut bytes).write_num_be(unsigned).unwrap();
-    /// assert_eq!(bytes, vec![0x00, 0x00, 0x05, 0x39]);
-    ///
-    /// let signed: i32 = -1337;
-    /// Cursor::new(&mut bytes).write_num_be(signed).unwrap();
-    /// assert_eq!(bytes, vec![0xFF, 0xFF, 0xFA, 0xC7]);
-    ///
-    /// let float: f32 = 133.7;
-    /// Cursor::new(&mut bytes).write_num_be(float).unwrap();
-    /// assert_eq!(bytes, vec![0x43, 0x05, 0xB3, 0x33]);
-    /// ```
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn write_num_be<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
-    where
-        N: num_traits::ToBytes<Bytes = [u8; SIZE]>,
-    {
-        self.write_all(&num.to_be_bytes())
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
     }
 
-    /// Write a number to bytes in little endian.
-    ///
-    /// For further semantics please refer to [`Write::write_all`].
-    ///
-    /// # Examples
-    /// ```
-    /// use rw_exact_ext::WriteAllExt;
-    /// use std::io::Cursor;
-    ///
-    /// let mut bytes = vec![0u8; 4];
-    ///
-    /// let unsigned: u32 = 1337;
-    /// Cursor::new(&mut bytes).write_num_le(unsigned).unwrap();
-    /// assert_eq!(bytes, vec![0x39, 0x05, 0x00, 0x00]);
-    ///
-    /// let signed: i32 = -1337;
-    /// Cursor::new(&mut bytes).write_num_le(signed).unwrap();
-    /// assert_eq!(bytes, vec![0xC7, 0xFA, 0xFF, 0xFF]);
-    ///
-    /// let float: f32 = 133.7;
-    /// Cursor::new(&mut bytes).write_num_le(float).unwrap();
-    /// assert_eq!(bytes, vec![0x33, 0xB3, 0x05, 0x43]);
-    /// ```
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn write_num_le<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
-    where
-        N: num_traits::ToBytes<Bytes = [u8; SIZE]>,
-    {
-        self.write_all(&num.to_le_bytes())
-    }
+    Ok(filled)
+}
 
-    /// Write a number to bytes in native endianness.
-    ///
-    /// For further semantics please refer to [`Write::write_all`].
-    #[cfg(feature = "num-traits")]
-    #[allow(clippy::missing_errors_doc)]
-    fn write_num_ne<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
-    where
-        N: num_traits::ToBytes<Bytes = [u8; SIZE]>,
-    {
-        self.write_all(&num.to_ne_bytes())
+/// Fill `buffer` completely, or return `Ok(false)` if the stream ends before the first byte.
+fn fill_or_eof<R>(reader: &mut R, buffer: &mut [u8]) -> Result<bool>
+where
+    R: Read + ?Sized,
+{
+    match fill(reader, buffer)? {
+        0 if !buffer.is_empty() => Ok(false),
+        filled if filled < buffer.len() => Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        )),
+        _ => Ok(true),
     }
 }
-
-impl<T> WriteAllExt for T where T: Write {}
@@ -0,0 +1,56 @@
+use std::fmt::{self, Display, Formatter};
+
+/// An EUI-48 MAC address.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::MacAddr;
+///
+/// let addr = MacAddr::from([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+/// assert_eq!(addr.to_string(), "00:1a:2b:3c:4d:5e");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MacAddr([u8; 6]);
+
+impl MacAddr {
+    /// The broadcast address `ff:ff:ff:ff:ff:ff`.
+    pub const BROADCAST: Self = Self([0xFF; 6]);
+
+    /// Create a new MAC address from its octets.
+    #[must_use]
+    pub const fn new(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+
+    /// Return the octets of the MAC address.
+    #[must_use]
+    pub const fn octets(self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl Display for MacAddr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, octet) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(":")?;
+            }
+
+            write!(f, "{octet:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    fn from(addr: MacAddr) -> Self {
+        addr.0
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{Read, Result, Write};
+use std::time::{Duration, Instant};
+
+/// Statistics of the I/O calls made through a [`MeteredReader`] or [`MeteredWriter`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TransferStats {
+    /// Amount of bytes transferred.
+    pub bytes: u64,
+    /// Amount of calls to the underlying reader or writer.
+    pub calls: u64,
+    /// Total time spent in calls to the underlying reader or writer.
+    pub elapsed: Duration,
+}
+
+impl TransferStats {
+    fn record<T>(
+        &mut self,
+        operation: impl FnOnce() -> Result<T>,
+        bytes: impl Fn(&T) -> usize,
+    ) -> Result<T> {
+        let start = Instant::now();
+        let result = operation();
+        self.elapsed += start.elapsed();
+        self.calls += 1;
+
+        if let Ok(value) = &result {
+            self.bytes += bytes(value) as u64;
+        }
+
+        result
+    }
+}
+
+/// A reader that records statistics about the reads made through it.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{MeteredReader, ReadBytesExt};
+///
+/// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+/// let mut reader = MeteredReader::new(&bytes[..]);
+/// let _: [u8; 3] = reader.read_array_exact().unwrap();
+/// let _: [u8; 1] = reader.read_array_exact().unwrap();
+/// assert_eq!(reader.stats().bytes, 4);
+/// assert_eq!(reader.stats().calls, 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MeteredReader<R> {
+    reader: R,
+    stats: TransferStats,
+}
+
+impl<R> MeteredReader<R> {
+    /// Create a new metered reader wrapping `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            stats: TransferStats::default(),
+        }
+    }
+
+    /// Return the statistics recorded so far.
+    pub const fn stats(&self) -> TransferStats {
+        self.stats
+    }
+
+    /// Reset the recorded statistics and return the previous ones.
+    pub fn reset_stats(&mut self) -> TransferStats {
+        std::mem::take(&mut self.stats)
+    }
+
+    /// Return a reference to the underlying reader.
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Return a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> Read for MeteredReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let reader = &mut self.reader;
+        self.stats.record(|| reader.read(buf), |&n| n)
+    }
+}
+
+/// A writer that records statistics about the writes made through it.
+///
+/// Flushes count towards the elapsed time, but not towards the calls.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{MeteredWriter, WriteNumExt};
+///
+/// let mut writer = MeteredWriter::new(Vec::new());
+/// writer.write_bool(true).unwrap();
+/// writer.write_bool(false).unwrap();
+/// assert_eq!(writer.stats().bytes, 2);
+/// assert_eq!(writer.stats().calls, 2);
+/// assert_eq!(writer.into_inner(), [0x01, 0x00]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MeteredWriter<W> {
+    writer: W,
+    stats: TransferStats,
+}
+
+impl<W> MeteredWriter<W> {
+    /// Create a new metered writer wrapping `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            stats: TransferStats::default(),
+        }
+    }
+
+    /// Return the statistics recorded so far.
+    pub const fn stats(&self) -> TransferStats {
+        self.stats
+    }
+
+    /// Reset the recorded statistics and return the previous ones.
+    pub fn reset_stats(&mut self) -> TransferStats {
+        std::mem::take(&mut self.stats)
+    }
+
+    /// Return a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> Write for MeteredWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let writer = &mut self.writer;
+        self.stats.record(|| writer.write(buf), |&n| n)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let start = Instant::now();
+        let result = self.writer.flush();
+        self.stats.elapsed += start.elapsed();
+        result
+    }
+}
@@ -0,0 +1,236 @@
+//! Readers and writers with scripted behavior, for testing code built on the extension traits.
+
+use std::collections::VecDeque;
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+/// A reader that yields its data in scripted chunk sizes and can fail at chosen offsets.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{MockReader, ReadBytesExt};
+/// use std::io::{ErrorKind, Read};
+///
+/// let mut reader = MockReader::new([0xAB, 0xCD, 0xEF, 0x42, 0x13, 0x37])
+///     .with_chunk_sizes([1, 2])
+///     .with_error_at(3, ErrorKind::Interrupted)
+///     .with_eof_at(5);
+///
+/// let mut buffer = [0; 8];
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 1);
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), ErrorKind::Interrupted);
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 0);
+///
+/// // `read_exact` retries on `Interrupted` and copes with short reads.
+/// let mut reader = MockReader::new([0xAB, 0xCD, 0xEF, 0x42])
+///     .with_chunk_sizes([1, 1, 1, 1])
+///     .with_error_at(2, ErrorKind::Interrupted);
+/// assert_eq!(reader.read_array_exact().unwrap(), [0xAB, 0xCD, 0xEF, 0x42]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockReader {
+    data: Vec<u8>,
+    position: usize,
+    end: usize,
+    chunk_sizes: VecDeque<usize>,
+    errors: VecDeque<(usize, ErrorKind)>,
+}
+
+impl MockReader {
+    /// Create a new mock reader yielding `data` without restrictions.
+    pub fn new(data: impl Into<Vec<u8>>) -> Self {
+        let data = data.into();
+        Self {
+            end: data.len(),
+            data,
+            position: 0,
+            chunk_sizes: VecDeque::new(),
+            errors: VecDeque::new(),
+        }
+    }
+
+    /// Limit the amount of bytes returned by consecutive reads.
+    ///
+    /// Each read consumes one chunk size. Once all chunk sizes are consumed, reads are unlimited.
+    #[must_use]
+    pub fn with_chunk_sizes(mut self, chunk_sizes: impl IntoIterator<Item = usize>) -> Self {
+        self.chunk_sizes.extend(chunk_sizes);
+        self
+    }
+
+    /// Fail the first read at the given offset once with an error of the given kind.
+    ///
+    /// Reads before the offset return at most the bytes up to the offset.
+    #[must_use]
+    pub fn with_error_at(mut self, offset: usize, kind: ErrorKind) -> Self {
+        let index = self.errors.partition_point(|&(other, _)| other <= offset);
+        self.errors.insert(index, (offset, kind));
+        self
+    }
+
+    /// Signal the end of the stream at the given offset, discarding any data after it.
+    #[must_use]
+    pub fn with_eof_at(mut self, offset: usize) -> Self {
+        self.end = self.end.min(offset);
+        self
+    }
+
+    /// Return the amount of bytes read so far.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Read for MockReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if let Some(&(offset, kind)) = self.errors.front() {
+            if offset <= self.position {
+                self.errors.pop_front();
+                return Err(Error::new(
+                    kind,
+                    format!("injected error at offset {offset}"),
+                ));
+            }
+        }
+
+        let mut limit = buf.len().min(self.end - self.position);
+
+        if let Some(&(offset, _)) = self.errors.front() {
+            limit = limit.min(offset - self.position);
+        }
+
+        if limit > 0 {
+            if let Some(chunk_size) = self.chunk_sizes.pop_front() {
+                limit = limit.min(chunk_size);
+            }
+        }
+
+        buf[..limit].copy_from_slice(&self.data[self.position..self.position + limit]);
+        self.position += limit;
+        Ok(limit)
+    }
+}
+
+/// A writer collecting its data into a `Vec<u8>` that accepts limited amounts of bytes
+/// and can fail at chosen offsets.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::FaultyWriter;
+/// use std::io::{ErrorKind, Write};
+///
+/// let mut writer = FaultyWriter::new()
+///     .with_max_write_size(2)
+///     .with_error_at(2, ErrorKind::Interrupted);
+/// assert_eq!(writer.write(&[0xAB, 0xCD, 0xEF]).unwrap(), 2);
+/// assert_eq!(writer.write(&[0xEF]).unwrap_err().kind(), ErrorKind::Interrupted);
+/// assert_eq!(writer.write(&[0xEF]).unwrap(), 1);
+/// assert_eq!(writer.written(), [0xAB, 0xCD, 0xEF]);
+///
+/// // `write_all` copes with short writes, but reports a full disk.
+/// let mut writer = FaultyWriter::new().with_max_write_size(1).with_capacity(3);
+/// writer.write_all(&[0xAB, 0xCD]).unwrap();
+/// let error = writer.write_all(&[0xEF, 0x42]).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::StorageFull);
+/// assert_eq!(writer.into_inner(), [0xAB, 0xCD, 0xEF]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FaultyWriter {
+    data: Vec<u8>,
+    max_write_size: Option<usize>,
+    capacity: Option<usize>,
+    errors: VecDeque<(usize, ErrorKind)>,
+}
+
+impl FaultyWriter {
+    /// Create a new faulty writer accepting every write completely.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept at most `max_write_size` bytes per write.
+    #[must_use]
+    pub const fn with_max_write_size(mut self, max_write_size: usize) -> Self {
+        self.max_write_size = Some(max_write_size);
+        self
+    }
+
+    /// Accept at most `capacity` bytes in total.
+    ///
+    /// Writes beyond the capacity fail with an error of kind [`ErrorKind::StorageFull`].
+    #[must_use]
+    pub const fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Fail the first write at the given offset once with an error of the given kind.
+    ///
+    /// Writes before the offset accept at most the bytes up to the offset.
+    #[must_use]
+    pub fn with_error_at(mut self, offset: usize, kind: ErrorKind) -> Self {
+        let index = self.errors.partition_point(|&(other, _)| other <= offset);
+        self.errors.insert(index, (offset, kind));
+        self
+    }
+
+    /// Return the bytes written so far.
+    #[must_use]
+    pub fn written(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Return the bytes written.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl Write for FaultyWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let position = self.data.len();
+
+        if let Some(&(offset, kind)) = self.errors.front() {
+            if offset <= position {
+                self.errors.pop_front();
+                return Err(Error::new(
+                    kind,
+                    format!("injected error at offset {offset}"),
+                ));
+            }
+        }
+
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut limit = buf.len();
+
+        if let Some(&(offset, _)) = self.errors.front() {
+            limit = limit.min(offset - position);
+        }
+
+        if let Some(max_write_size) = self.max_write_size {
+            limit = limit.min(max_write_size);
+        }
+
+        if let Some(capacity) = self.capacity {
+            if position >= capacity {
+                return Err(Error::new(ErrorKind::StorageFull, "capacity exhausted"));
+            }
+
+            limit = limit.min(capacity - position);
+        }
+
+        self.data.extend_from_slice(&buf[..limit]);
+        Ok(limit)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
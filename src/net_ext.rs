@@ -0,0 +1,340 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+use crate::{MacAddr, ReadBytesExt, WriteBytesExt};
+
+/// Exact reads of network addresses and identifiers.
+pub trait ReadNetExt: ReadBytesExt {
+    /// Read a UUID in its standard big-endian 16-byte layout.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use uuid::uuid;
+    ///
+    /// let bytes = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8").into_bytes();
+    /// let uuid = Cursor::new(&bytes).read_uuid().unwrap();
+    /// assert_eq!(uuid, uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_uuid(&mut self) -> Result<uuid::Uuid> {
+        self.read_array_exact().map(uuid::Uuid::from_bytes)
+    }
+
+    /// Read a UUID in the Microsoft GUID layout.
+    ///
+    /// In this layout, the first three fields are stored in little endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use uuid::uuid;
+    ///
+    /// let bytes = [
+    ///     0x44, 0x50, 0xE5, 0x67, 0xB1, 0x10, 0x6F, 0x42,
+    ///     0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0, 0xC8,
+    /// ];
+    /// let uuid = Cursor::new(&bytes).read_guid().unwrap();
+    /// assert_eq!(uuid, uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_guid(&mut self) -> Result<uuid::Uuid> {
+        self.read_array_exact().map(uuid::Uuid::from_bytes_le)
+    }
+
+    /// Read an IPv4 address in network byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let bytes = [192, 168, 0, 1];
+    /// let addr = Cursor::new(&bytes).read_ipv4_addr().unwrap();
+    /// assert_eq!(addr, Ipv4Addr::new(192, 168, 0, 1));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_ipv4_addr(&mut self) -> Result<Ipv4Addr> {
+        self.read_array_exact().map(Ipv4Addr::from)
+    }
+
+    /// Read an IPv6 address in network byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use std::net::Ipv6Addr;
+    ///
+    /// let bytes = Ipv6Addr::LOCALHOST.octets();
+    /// let addr = Cursor::new(&bytes).read_ipv6_addr().unwrap();
+    /// assert_eq!(addr, Ipv6Addr::LOCALHOST);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_ipv6_addr(&mut self) -> Result<Ipv6Addr> {
+        self.read_array_exact().map(Ipv6Addr::from)
+    }
+
+    /// Read an IP address preceded by a family byte.
+    ///
+    /// The family byte is `4` for IPv4 and `6` for IPv6 addresses.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] on any other family byte.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let bytes = [4, 10, 0, 0, 1];
+    /// let addr = Cursor::new(&bytes).read_ip_addr().unwrap();
+    /// assert_eq!(addr, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+    /// assert!(Cursor::new(&[5]).read_ip_addr().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_ip_addr(&mut self) -> Result<IpAddr> {
+        match self.read_array_exact()? {
+            [4] => self.read_ipv4_addr().map(IpAddr::V4),
+            [6] => self.read_ipv6_addr().map(IpAddr::V6),
+            [family] => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid IP address family: {family}"),
+            )),
+        }
+    }
+
+    /// Read an IPv4 socket address as address followed by port, both in network byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
+    ///
+    /// let bytes = [127, 0, 0, 1, 0x1F, 0x90];
+    /// let addr = Cursor::new(&bytes).read_socket_addr_v4().unwrap();
+    /// assert_eq!(addr, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_socket_addr_v4(&mut self) -> Result<SocketAddrV4> {
+        let ip = self.read_ipv4_addr()?;
+        let port = self.read_array_exact().map(u16::from_be_bytes)?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+
+    /// Read an IPv6 socket address as address followed by port, both in network byte order.
+    ///
+    /// Flow info and scope ID are not part of the encoding and set to zero.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_socket_addr_v6(&mut self) -> Result<SocketAddrV6> {
+        let ip = self.read_ipv6_addr()?;
+        let port = self.read_array_exact().map(u16::from_be_bytes)?;
+        Ok(SocketAddrV6::new(ip, port, 0, 0))
+    }
+
+    /// Read an EUI-48 MAC address.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNetExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+    /// let addr = Cursor::new(&bytes).read_mac48().unwrap();
+    /// assert_eq!(addr.octets(), bytes);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_mac48(&mut self) -> Result<MacAddr> {
+        self.read_array_exact().map(MacAddr::new)
+    }
+
+    /// Read an EUI-64 identifier.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_eui64(&mut self) -> Result<[u8; 8]> {
+        self.read_array_exact()
+    }
+}
+
+impl<T> ReadNetExt for T where T: Read {}
+
+/// Writes of network addresses and identifiers.
+pub trait WriteNetExt: WriteBytesExt {
+    /// Write a UUID in its standard big-endian 16-byte layout.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNetExt;
+    /// use uuid::uuid;
+    ///
+    /// let uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// let mut bytes = Vec::new();
+    /// bytes.write_uuid(&uuid).unwrap();
+    /// assert_eq!(bytes, uuid.as_bytes());
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_uuid(&mut self, uuid: &uuid::Uuid) -> Result<()> {
+        self.write_all(uuid.as_bytes())
+    }
+
+    /// Write a UUID in the Microsoft GUID layout.
+    ///
+    /// In this layout, the first three fields are stored in little endian.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNetExt;
+    /// use uuid::uuid;
+    ///
+    /// let uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// let mut bytes = Vec::new();
+    /// bytes.write_guid(&uuid).unwrap();
+    /// assert_eq!(bytes[..8], [0x44, 0x50, 0xE5, 0x67, 0xB1, 0x10, 0x6F, 0x42]);
+    /// assert_eq!(bytes[8..], uuid.as_bytes()[8..]);
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_guid(&mut self, uuid: &uuid::Uuid) -> Result<()> {
+        self.write_all(&uuid.to_bytes_le())
+    }
+
+    /// Write an IPv4 address in network byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNetExt;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_ipv4_addr(Ipv4Addr::new(192, 168, 0, 1)).unwrap();
+    /// assert_eq!(bytes, [192, 168, 0, 1]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_ipv4_addr(&mut self, addr: Ipv4Addr) -> Result<()> {
+        self.write_all(&addr.octets())
+    }
+
+    /// Write an IPv6 address in network byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_ipv6_addr(&mut self, addr: Ipv6Addr) -> Result<()> {
+        self.write_all(&addr.octets())
+    }
+
+    /// Write an IP address preceded by a family byte.
+    ///
+    /// The family byte is `4` for IPv4 and `6` for IPv6 addresses.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNetExt;
+    /// use std::net::{IpAddr, Ipv6Addr};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_ip_addr(IpAddr::V6(Ipv6Addr::LOCALHOST)).unwrap();
+    /// assert_eq!(bytes[0], 6);
+    /// assert_eq!(bytes[1..], Ipv6Addr::LOCALHOST.octets());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_ip_addr(&mut self, addr: IpAddr) -> Result<()> {
+        match addr {
+            IpAddr::V4(addr) => {
+                self.write_all(&[4])?;
+                self.write_ipv4_addr(addr)
+            }
+            IpAddr::V6(addr) => {
+                self.write_all(&[6])?;
+                self.write_ipv6_addr(addr)
+            }
+        }
+    }
+
+    /// Write an IPv4 socket address as address followed by port, both in network byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNetExt;
+    /// use std::net::{Ipv4Addr, SocketAddrV4};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_socket_addr_v4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)).unwrap();
+    /// assert_eq!(bytes, [127, 0, 0, 1, 0x1F, 0x90]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_socket_addr_v4(&mut self, addr: SocketAddrV4) -> Result<()> {
+        self.write_ipv4_addr(*addr.ip())?;
+        self.write_all(&addr.port().to_be_bytes())
+    }
+
+    /// Write an IPv6 socket address as address followed by port, both in network byte order.
+    ///
+    /// Flow info and scope ID are not part of the encoding.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_socket_addr_v6(&mut self, addr: SocketAddrV6) -> Result<()> {
+        self.write_ipv6_addr(*addr.ip())?;
+        self.write_all(&addr.port().to_be_bytes())
+    }
+
+    /// Write an EUI-48 MAC address.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{MacAddr, WriteNetExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_mac48(MacAddr::BROADCAST).unwrap();
+    /// assert_eq!(bytes, [0xFF; 6]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_mac48(&mut self, addr: MacAddr) -> Result<()> {
+        self.write_all(&addr.octets())
+    }
+
+    /// Write an EUI-64 identifier.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_eui64(&mut self, eui: [u8; 8]) -> Result<()> {
+        self.write_all(&eui)
+    }
+}
+
+impl<T> WriteNetExt for T where T: Write {}
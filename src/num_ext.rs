@@ -0,0 +1,1854 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::sync::atomic::Ordering;
+
+use crate::protobuf::MAX_FIELD_NUMBER;
+use crate::{
+    check_size, fill_vec, AtomicInteger, Endianness, FloatPolicy, FromWireBytes, ReadBytesExt,
+    ReadFrom, ToWireBytes, WireType, WriteBytesExt, WriteTo,
+};
+
+/// Exact reads of numbers.
+///
+/// With the feature `bytes` enabled, the methods of this trait are available on `bytes::Buf`s
+/// through the [`Read`] implementor returned by `Buf::reader`.
+/// The trait is not implemented for `Buf`s directly.
+pub trait ReadNumExt: ReadBytesExt {
+    /// Read one byte and interpret it as a `bool`.
+    ///
+    /// Returns `true` if the read byte is non-zero, or `false` otherwise.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x01, 0x00, 0xEF, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert!(cursor.read_bool().unwrap());
+    /// assert!(!cursor.read_bool().unwrap());
+    /// assert!(cursor.read_bool().unwrap());
+    /// assert!(cursor.read_bool().unwrap());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bool(&mut self) -> Result<bool> {
+        self.read_array_exact::<1>().map(|[byte]| byte != 0)
+    }
+
+    /// Read a number from a byte array in big endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    ///
+    /// let unsigned: u32 = Cursor::new(&bytes).read_num_be().unwrap();
+    /// assert_eq!(unsigned, 0xABCDEF42);
+    ///
+    /// let signed: i32 = Cursor::new(&bytes).read_num_be().unwrap();
+    /// assert_eq!(signed, -0x543210BE);
+    ///
+    /// let float: f32 = Cursor::new(&bytes).read_num_be().unwrap();
+    /// assert_eq!(float, -1.4632533e-12);
+    /// ```
+    ///
+    /// With the feature `fixed` enabled, fixed-point numbers can be read as well:
+    /// ```
+    /// # #[cfg(feature = "fixed")]
+    /// # {
+    /// use fixed::types::{I16F16, U8F8};
+    /// use rw_exact_ext::{ReadNumExt, WriteNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFF, 0xFE, 0x80, 0x00, 0x01, 0x80];
+    /// let mut cursor = Cursor::new(&bytes);
+    ///
+    /// let signed: I16F16 = cursor.read_num_be().unwrap();
+    /// assert_eq!(signed, I16F16::from_num(-1.5));
+    ///
+    /// let unsigned: U8F8 = cursor.read_num_be().unwrap();
+    /// assert_eq!(unsigned, U8F8::from_num(1.5));
+    ///
+    /// let mut written = Vec::new();
+    /// written.write_num_be(signed).unwrap();
+    /// written.write_num_be(unsigned).unwrap();
+    /// assert_eq!(written, bytes);
+    /// # }
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_be<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Big))
+    }
+
+    /// Read a number from a byte array in little endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+    ///
+    /// let unsigned: u32 = Cursor::new(&bytes).read_num_le().unwrap();
+    /// assert_eq!(unsigned, 0x42EFCDAB);
+    ///
+    /// let signed: i32 = Cursor::new(&bytes).read_num_le().unwrap();
+    /// assert_eq!(signed, 0x42EFCDAB);
+    ///
+    /// let float: f32 = Cursor::new(&bytes).read_num_le().unwrap();
+    /// assert_eq!(float, 119.901695);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_le<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Little))
+    }
+
+    /// Read a number from a byte array in native endianness.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_ne<N, const SIZE: usize>(&mut self) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact()
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Native))
+    }
+
+    /// Read a `rust_decimal::Decimal` from 16 bytes in big endian.
+    ///
+    /// The layout consists of a `u32` of flags followed by the 96-bit unsigned mantissa.
+    /// Bits 16 to 23 of the flags hold the scale and bit 31 holds the sign,
+    /// all other bits must be zero.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the flags are invalid.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [
+    ///     0x80, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39,
+    /// ];
+    /// let decimal = Cursor::new(&bytes).read_decimal_be().unwrap();
+    /// assert_eq!(decimal, Decimal::new(-12345, 2));
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_decimal_be(&mut self) -> Result<rust_decimal::Decimal> {
+        let flags = self.read_array_exact().map(u32::from_be_bytes)?;
+        let hi = self.read_array_exact().map(u32::from_be_bytes)?;
+        let mid = self.read_array_exact().map(u32::from_be_bytes)?;
+        let lo = self.read_array_exact().map(u32::from_be_bytes)?;
+        decimal_from_parts(flags, lo, mid, hi)
+    }
+
+    /// Read a `rust_decimal::Decimal` from 16 bytes in little endian.
+    ///
+    /// The layout consists of a `u32` of flags followed by the 96-bit unsigned mantissa.
+    /// Bits 16 to 23 of the flags hold the scale and bit 31 holds the sign,
+    /// all other bits must be zero.
+    /// This is the layout of [`rust_decimal::Decimal::serialize`].
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the flags are invalid.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = Decimal::new(-12345, 2).serialize();
+    /// let decimal = Cursor::new(&bytes).read_decimal_le().unwrap();
+    /// assert_eq!(decimal, Decimal::new(-12345, 2));
+    ///
+    /// let mut bytes = bytes;
+    /// bytes[2] = 29;
+    /// assert!(Cursor::new(&bytes).read_decimal_le().is_err());
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_decimal_le(&mut self) -> Result<rust_decimal::Decimal> {
+        let flags = self.read_array_exact().map(u32::from_le_bytes)?;
+        let lo = self.read_array_exact().map(u32::from_le_bytes)?;
+        let mid = self.read_array_exact().map(u32::from_le_bytes)?;
+        let hi = self.read_array_exact().map(u32::from_le_bytes)?;
+        decimal_from_parts(flags, lo, mid, hi)
+    }
+
+    /// Read an unsigned big integer of `size` bytes in big endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let num = Cursor::new(&bytes).read_biguint_be(bytes.len()).unwrap();
+    /// assert_eq!(num, BigUint::from(u64::MAX) + 1u8);
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_biguint_be(&mut self, size: usize) -> Result<num_bigint::BigUint> {
+        self.read_vec_exact(size)
+            .map(|bytes| num_bigint::BigUint::from_bytes_be(&bytes))
+    }
+
+    /// Read an unsigned big integer of `size` bytes in little endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_biguint_le(&mut self, size: usize) -> Result<num_bigint::BigUint> {
+        self.read_vec_exact(size)
+            .map(|bytes| num_bigint::BigUint::from_bytes_le(&bytes))
+    }
+
+    /// Read a signed big integer of `size` bytes in two's complement big endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFF, 0x7F];
+    /// let num = Cursor::new(&bytes).read_bigint_be(bytes.len()).unwrap();
+    /// assert_eq!(num, BigInt::from(-129));
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bigint_be(&mut self, size: usize) -> Result<num_bigint::BigInt> {
+        self.read_vec_exact(size)
+            .map(|bytes| num_bigint::BigInt::from_signed_bytes_be(&bytes))
+    }
+
+    /// Read a signed big integer of `size` bytes in two's complement little endian.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bigint_le(&mut self, size: usize) -> Result<num_bigint::BigInt> {
+        self.read_vec_exact(size)
+            .map(|bytes| num_bigint::BigInt::from_signed_bytes_le(&bytes))
+    }
+
+    /// Read an unsigned big integer in big endian, preceded by its size as `u32` in big endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the size exceeds `max_size`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x00, 0x00, 0x03, 0x01, 0x00, 0x01];
+    /// let num = Cursor::new(&bytes).read_biguint_be_prefixed(Some(8)).unwrap();
+    /// assert_eq!(num, BigUint::from(65537u32));
+    /// assert!(Cursor::new(&bytes).read_biguint_be_prefixed(Some(2)).is_err());
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_biguint_be_prefixed(&mut self, max_size: Option<usize>) -> Result<num_bigint::BigUint> {
+        let size = self.read_array_exact().map(u32::from_be_bytes)? as usize;
+        check_size(size, max_size)?;
+        self.read_biguint_be(size)
+    }
+
+    /// Read a signed big integer in two's complement big endian,
+    /// preceded by its size as `u32` in big endian.
+    ///
+    /// This is the `mpint` encoding of RFC 4251.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the size exceeds `max_size`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bigint_be_prefixed(&mut self, max_size: Option<usize>) -> Result<num_bigint::BigInt> {
+        let size = self.read_array_exact().map(u32::from_be_bytes)? as usize;
+        check_size(size, max_size)?;
+        self.read_bigint_be(size)
+    }
+
+    /// Read an unsigned integer of `digits` decimal digits in packed BCD.
+    ///
+    /// Each byte holds two digits, most significant digit first.
+    /// For an odd amount of digits, the high nibble of the first byte is padding and must be zero.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if a nibble is not a valid digit
+    /// or if the value exceeds `u64::MAX`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// assert_eq!(Cursor::new(&[0x12, 0x34]).read_bcd(4).unwrap(), 1234);
+    /// assert_eq!(Cursor::new(&[0x01, 0x23]).read_bcd(3).unwrap(), 123);
+    /// assert!(Cursor::new(&[0x1A]).read_bcd(2).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bcd(&mut self, digits: usize) -> Result<u64> {
+        let bytes = self.read_vec_exact(digits.div_ceil(2))?;
+
+        if digits % 2 == 1 && bytes[0] >> 4 != 0 {
+            return Err(Error::new(ErrorKind::InvalidData, "non-zero BCD padding"));
+        }
+
+        bytes
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0x0F])
+            .skip(digits % 2)
+            .try_fold(0u64, |value, digit| {
+                if digit > 9 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("invalid BCD digit: {digit:#x}"),
+                    ));
+                }
+
+                value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit.into()))
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, "BCD value exceeds u64"))
+            })
+    }
+
+    /// Read an unsigned LEB128 variable-length integer, as used by Protocol Buffers and WebAssembly.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value overflows `u64`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x2A, 0xAC, 0x02, 0x80];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_varint_u64().unwrap(), 42);
+    /// assert_eq!(cursor.read_varint_u64().unwrap(), 300);
+    /// assert!(cursor.read_varint_u64().is_err());
+    ///
+    /// assert!(Cursor::new([0xFF; 11]).read_varint_u64().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_varint_u64(&mut self) -> Result<u64> {
+        let mut value = 0;
+
+        for shift in (0..64).step_by(7) {
+            let [byte] = self.read_array_exact()?;
+
+            if shift == 63 && byte > 1 {
+                break;
+            }
+
+            value |= u64::from(byte & 0x7F) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Error::new(ErrorKind::InvalidData, "varint overflows u64"))
+    }
+
+    /// Read a `Vec<u8>` prefixed with its size as unsigned LEB128 variable-length integer.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the size does not fit into `usize`
+    /// or exceeds `max_size`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x03, 0xAB, 0xCD, 0xEF, 0x02, 0x42];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_vec_varint_prefixed(Some(16)).unwrap(), [0xAB, 0xCD, 0xEF]);
+    /// assert!(cursor.read_vec_varint_prefixed(None).is_err());
+    /// assert!(Cursor::new(&bytes).read_vec_varint_prefixed(Some(2)).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_vec_varint_prefixed(&mut self, max_size: Option<usize>) -> Result<Vec<u8>> {
+        let size = usize::try_from(self.read_varint_u64()?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        check_size(size, max_size)?;
+        self.read_vec_exact(size)
+    }
+
+    /// Read `count` unsigned integers of `bits` bits each, packed contiguously with the most
+    /// significant bit first.
+    ///
+    /// The packed bits are read at once and unpacked word by word, with dedicated paths
+    /// for the common widths of 10 and 12 bits. Padding bits after the last value up to
+    /// the next byte boundary are discarded.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `bits` is not within `1..=32`
+    /// or the total amount of bits overflows `usize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// // Two 12-bit samples 0xABC and 0xDEF.
+    /// let bytes = [0xAB, 0xCD, 0xEF];
+    /// let samples = Cursor::new(&bytes).read_packed_uints_be(2, 12).unwrap();
+    /// assert_eq!(samples, [0xABC, 0xDEF]);
+    /// assert!(Cursor::new(&bytes).read_packed_uints_be(3, 12).is_err());
+    ///
+    /// // Four 10-bit samples.
+    /// let samples = Cursor::new([0x00, 0x40, 0x20, 0x0F, 0xFF])
+    ///     .read_packed_uints_be(4, 10)
+    ///     .unwrap();
+    /// assert_eq!(samples, [1, 2, 3, 1023]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_packed_uints_be(&mut self, count: usize, bits: u32) -> Result<Vec<u32>> {
+        read_packed_uints(self, count, bits, true)
+    }
+
+    /// Read `count` unsigned integers of `bits` bits each, packed contiguously with the least
+    /// significant bit first.
+    ///
+    /// The packed bits are read at once and unpacked word by word, with dedicated paths
+    /// for the common widths of 10 and 12 bits. Padding bits after the last value up to
+    /// the next byte boundary are discarded.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `bits` is not within `1..=32`
+    /// or the total amount of bits overflows `usize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// // Two 12-bit samples 0xABC and 0xDEF.
+    /// let bytes = [0xBC, 0xFA, 0xDE];
+    /// let samples = Cursor::new(&bytes).read_packed_uints_le(2, 12).unwrap();
+    /// assert_eq!(samples, [0xABC, 0xDEF]);
+    ///
+    /// // Four 6-bit indices.
+    /// let indices = Cursor::new([0b1100_0001, 0b0011_0000, 0b1111_1100])
+    ///     .read_packed_uints_le(4, 6)
+    ///     .unwrap();
+    /// assert_eq!(indices, [1, 3, 3, 63]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_packed_uints_le(&mut self, count: usize, bits: u32) -> Result<Vec<u32>> {
+        read_packed_uints(self, count, bits, false)
+    }
+
+    /// Read an `f32` in big endian, rejecting the values excluded by `policy`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value is rejected.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{FloatPolicy, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = 1.5_f32.to_be_bytes();
+    /// let float = Cursor::new(bytes).read_f32_be_strict(FloatPolicy::NORMAL).unwrap();
+    /// assert_eq!(float, 1.5);
+    ///
+    /// let subnormal = f32::from_bits(1).to_be_bytes();
+    /// assert!(Cursor::new(subnormal).read_f32_be_strict(FloatPolicy::FINITE).is_ok());
+    /// assert!(Cursor::new(subnormal).read_f32_be_strict(FloatPolicy::NORMAL).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_f32_be_strict(&mut self, policy: FloatPolicy) -> Result<f32> {
+        let float = f32::from_be_bytes(self.read_array_exact()?);
+        policy.check(float.classify()).map(|()| float)
+    }
+
+    /// Read an `f32` in little endian, rejecting the values excluded by `policy`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value is rejected.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{FloatPolicy, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = f32::INFINITY.to_le_bytes();
+    /// assert!(Cursor::new(bytes).read_f32_le_strict(FloatPolicy::FINITE).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_f32_le_strict(&mut self, policy: FloatPolicy) -> Result<f32> {
+        let float = f32::from_le_bytes(self.read_array_exact()?);
+        policy.check(float.classify()).map(|()| float)
+    }
+
+    /// Read an `f64` in big endian, rejecting the values excluded by `policy`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value is rejected.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{FloatPolicy, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = (-0.25_f64).to_be_bytes();
+    /// let float = Cursor::new(bytes).read_f64_be_strict(FloatPolicy::NORMAL).unwrap();
+    /// assert_eq!(float, -0.25);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_f64_be_strict(&mut self, policy: FloatPolicy) -> Result<f64> {
+        let float = f64::from_be_bytes(self.read_array_exact()?);
+        policy.check(float.classify()).map(|()| float)
+    }
+
+    /// Read an `f64` in little endian, rejecting the values excluded by `policy`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value is rejected.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{FloatPolicy, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = f64::NAN.to_le_bytes();
+    /// assert!(Cursor::new(bytes).read_f64_le_strict(FloatPolicy::FINITE).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_f64_le_strict(&mut self, policy: FloatPolicy) -> Result<f64> {
+        let float = f64::from_le_bytes(self.read_array_exact()?);
+        policy.check(float.classify()).map(|()| float)
+    }
+
+    /// Read an unsigned integer of `size` bytes in big endian, where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x12, 0x34, 0x56];
+    /// assert_eq!(Cursor::new(&bytes).read_uint_be(3).unwrap(), 0x12_3456);
+    /// assert!(Cursor::new(&bytes).read_uint_be(9).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_uint_be(&mut self, size: usize) -> Result<u64> {
+        let mut bytes = [0; 8];
+        self.read_exact(&mut bytes[8 - check_int_size(size)?..])?;
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Read an unsigned integer of `size` bytes in little endian, where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x12, 0x34, 0x56];
+    /// assert_eq!(Cursor::new(&bytes).read_uint_le(3).unwrap(), 0x56_3412);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_uint_le(&mut self, size: usize) -> Result<u64> {
+        let mut bytes = [0; 8];
+        self.read_exact(&mut bytes[..check_int_size(size)?])?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Read a signed two's complement integer of `size` bytes in big endian,
+    /// where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFF, 0xFF, 0xFE];
+    /// assert_eq!(Cursor::new(&bytes).read_int_be(3).unwrap(), -2);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_int_be(&mut self, size: usize) -> Result<i64> {
+        self.read_uint_be(size)
+            .map(|value| sign_extend(value, size))
+    }
+
+    /// Read a signed two's complement integer of `size` bytes in little endian,
+    /// where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFE, 0xFF, 0x7F];
+    /// assert_eq!(Cursor::new(&bytes[..2]).read_int_le(2).unwrap(), -2);
+    /// assert_eq!(Cursor::new(&bytes).read_int_le(3).unwrap(), 0x7F_FFFE);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_int_le(&mut self, size: usize) -> Result<i64> {
+        self.read_uint_le(size)
+            .map(|value| sign_extend(value, size))
+    }
+
+    /// Read an unsigned integer of type `P` in the given byte order and convert it into a `usize`.
+    ///
+    /// The width of the field on the wire is given by `P`, independently of the target platform.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value does not fit into `usize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x00, 0x01, 0x00];
+    /// assert_eq!(Cursor::new(&bytes).read_usize::<u32>(Endianness::Big).unwrap(), 256);
+    /// assert!(Cursor::new([0xFF; 16]).read_usize::<u128>(Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_usize<P>(&mut self, endianness: Endianness) -> Result<usize>
+    where
+        P: ReadFrom + TryInto<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a signed integer of type `P` in the given byte order and convert it into an `isize`.
+    ///
+    /// The width of the field on the wire is given by `P`, independently of the target platform.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value does not fit into `isize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadNumExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFF, 0xFF, 0xFF, 0xFE];
+    /// assert_eq!(Cursor::new(&bytes).read_isize::<i32>(Endianness::Big).unwrap(), -2);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_isize<P>(&mut self, endianness: Endianness) -> Result<isize>
+    where
+        P: ReadFrom + TryInto<isize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a length stored as `u16` in big endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u16_be(&mut self) -> Result<usize> {
+        self.read_usize::<u16>(Endianness::Big)
+    }
+
+    /// Read a length stored as `u16` in little endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u16_le(&mut self) -> Result<usize> {
+        self.read_usize::<u16>(Endianness::Little)
+    }
+
+    /// Read a length stored as `u32` in big endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x00, 0x00, 0x00, 0x03, b'a', b'b', b'c']);
+    /// let len = cursor.read_len_u32_be().unwrap();
+    /// assert_eq!(len, 3);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u32_be(&mut self) -> Result<usize> {
+        self.read_usize::<u32>(Endianness::Big)
+    }
+
+    /// Read a length stored as `u32` in little endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u32_le(&mut self) -> Result<usize> {
+        self.read_usize::<u32>(Endianness::Little)
+    }
+
+    /// Read a length stored as `u64` in big endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u64_be(&mut self) -> Result<usize> {
+        self.read_usize::<u64>(Endianness::Big)
+    }
+
+    /// Read a length stored as `u64` in little endian and convert it into a `usize`.
+    ///
+    /// For further semantics please refer to [`ReadNumExt::read_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_len_u64_le(&mut self) -> Result<usize> {
+        self.read_usize::<u64>(Endianness::Little)
+    }
+
+    /// Read a `bool` array of a constant size, packed into `SIZE.div_ceil(8)` bytes
+    /// with the most significant bit first.
+    ///
+    /// Padding bits after the last value up to the next byte boundary are discarded.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let present: [bool; 10] = Cursor::new([0b1010_0000, 0b0100_0000])
+    ///     .read_bool_array_packed_be()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     present,
+    ///     [true, false, true, false, false, false, false, false, false, true]
+    /// );
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bool_array_packed_be<const SIZE: usize>(&mut self) -> Result<[bool; SIZE]> {
+        read_bool_array_packed(self, true)
+    }
+
+    /// Read a `bool` array of a constant size, packed into `SIZE.div_ceil(8)` bytes
+    /// with the least significant bit first.
+    ///
+    /// Padding bits after the last value up to the next byte boundary are discarded.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let present: [bool; 4] = Cursor::new([0b0000_0101]).read_bool_array_packed_le().unwrap();
+    /// assert_eq!(present, [true, false, true, false]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_bool_array_packed_le<const SIZE: usize>(&mut self) -> Result<[bool; SIZE]> {
+        read_bool_array_packed(self, false)
+    }
+
+    /// Read a ZigZag-encoded signed LEB128 variable-length integer, as used by Protocol Buffers' `sint64`.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the value overflows `i64`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x00, 0x01, 0x02, 0x03]);
+    /// assert_eq!(cursor.read_varint_i64().unwrap(), 0);
+    /// assert_eq!(cursor.read_varint_i64().unwrap(), -1);
+    /// assert_eq!(cursor.read_varint_i64().unwrap(), 1);
+    /// assert_eq!(cursor.read_varint_i64().unwrap(), -2);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_varint_i64(&mut self) -> Result<i64> {
+        let value = self.read_varint_u64()?;
+        Ok((value >> 1).cast_signed() ^ -(value & 1).cast_signed())
+    }
+
+    /// Read a Protocol Buffers field key and return its field number and wire type.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the wire type is unknown
+    /// or the field number is not within `1..=536_870_911`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadNumExt, WireType};
+    /// use std::io::Cursor;
+    ///
+    /// assert_eq!(Cursor::new([0x08]).read_protobuf_key().unwrap(), (1, WireType::Varint));
+    /// assert_eq!(Cursor::new([0x92, 0x01]).read_protobuf_key().unwrap(), (18, WireType::Len));
+    /// assert!(Cursor::new([0x07]).read_protobuf_key().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_protobuf_key(&mut self) -> Result<(u32, WireType)> {
+        let key = self.read_varint_u64()?;
+        let wire_type = WireType::from_key(key)?;
+        let field_number = u32::try_from(key >> 3)
+            .ok()
+            .filter(|field_number| (1..=MAX_FIELD_NUMBER).contains(field_number))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid protobuf field number: {}", key >> 3),
+                )
+            })?;
+        Ok((field_number, wire_type))
+    }
+
+    /// Skip the value of a Protocol Buffers field of the given number and wire type,
+    /// whose key has already been read.
+    ///
+    /// Groups are skipped including all nested fields up to the matching end of the group.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if an end of a group is encountered
+    /// without a matching start, or if its field number differs from that of the start.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadNumExt, WireType};
+    /// use std::io::Cursor;
+    ///
+    /// // Group 1 containing a fixed 32-bit field, followed by the varint 42.
+    /// let bytes = [0x0D, 0x01, 0x02, 0x03, 0x04, 0x0C, 0x2A];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// cursor.skip_protobuf_field(1, WireType::SGroup).unwrap();
+    /// assert_eq!(cursor.read_varint_u64().unwrap(), 42);
+    ///
+    /// // The group is ended by the end of group 1, not group 2.
+    /// assert!(Cursor::new(&bytes).skip_protobuf_field(2, WireType::SGroup).is_err());
+    /// assert!(Cursor::new(&bytes).skip_protobuf_field(1, WireType::EGroup).is_err());
+    ///
+    /// // Group 1 containing group 2, which contains the varint 7.
+    /// let nested = [0x13, 0x08, 0x07, 0x14, 0x0C];
+    /// let mut cursor = Cursor::new(&nested);
+    /// cursor.skip_protobuf_field(1, WireType::SGroup).unwrap();
+    /// assert_eq!(cursor.position(), 5);
+    ///
+    /// let mismatched = [0x13, 0x0C, 0x14];
+    /// assert!(Cursor::new(&mismatched).skip_protobuf_field(1, WireType::SGroup).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn skip_protobuf_field(
+        &mut self,
+        mut field_number: u32,
+        mut wire_type: WireType,
+    ) -> Result<()> {
+        let mut groups = Vec::new();
+
+        loop {
+            match wire_type {
+                WireType::Varint => {
+                    self.read_varint_u64()?;
+                }
+                WireType::I64 => {
+                    self.read_array_exact::<8>()?;
+                }
+                WireType::Len => {
+                    let size = self.read_varint_u64()?;
+                    self.copy_exact(&mut std::io::sink(), size)?;
+                }
+                WireType::SGroup => groups.push(field_number),
+                WireType::EGroup => match groups.pop() {
+                    Some(start) if start == field_number => {}
+                    Some(start) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("protobuf group {start} ended by end of group {field_number}"),
+                        ));
+                    }
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            "unexpected protobuf group end",
+                        ));
+                    }
+                },
+                WireType::I32 => {
+                    self.read_array_exact::<4>()?;
+                }
+            }
+
+            if groups.is_empty() {
+                return Ok(());
+            }
+
+            (field_number, wire_type) = self.read_protobuf_key()?;
+        }
+    }
+}
+
+impl<T> ReadNumExt for T where T: Read {}
+
+/// Writes of numbers.
+///
+/// With the feature `bytes` enabled, the methods of this trait are available on `bytes::BufMut`s
+/// through the [`Write`] implementor returned by `BufMut::writer`.
+/// The trait is not implemented for `BufMut`s directly.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "bytes")]
+/// # {
+/// use bytes::{BufMut, BytesMut};
+/// use rw_exact_ext::WriteNumExt;
+///
+/// let mut writer = BytesMut::new().writer();
+/// writer.write_bool(true).unwrap();
+/// writer.write_num_be(0x1337_u16).unwrap();
+/// assert_eq!(&writer.into_inner()[..], [0x01, 0x13, 0x37]);
+/// # }
+/// ```
+pub trait WriteNumExt: WriteBytesExt {
+    /// Write a `bool` as one byte.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut bytes = vec![0xFF];
+    ///
+    /// Cursor::new(&mut bytes).write_bool(true).unwrap();
+    /// assert_eq!(bytes, [0x01]);
+    ///
+    /// Cursor::new(&mut bytes).write_bool(false).unwrap();
+    /// assert_eq!(bytes, [0x00]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bool(&mut self, boolean: bool) -> Result<()> {
+        self.write_all(&[boolean.into()])
+    }
+
+    /// Write a number to bytes in big endian.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut bytes = vec![0u8; 4];
+    ///
+    /// let unsigned: u32 = 1337;
+    /// Cursor::new(&mut bytes).write_num_be(unsigned).unwrap();
+    /// assert_eq!(bytes, vec![0x00, 0x00, 0x05, 0x39]);
+    ///
+    /// let signed: i32 = -1337;
+    /// Cursor::new(&mut bytes).write_num_be(signed).unwrap();
+    /// assert_eq!(bytes, vec![0xFF, 0xFF, 0xFA, 0xC7]);
+    ///
+    /// let float: f32 = 133.7;
+    /// Cursor::new(&mut bytes).write_num_be(float).unwrap();
+    /// assert_eq!(bytes, vec![0x43, 0x05, 0xB3, 0x33]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_be<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.write_all(&num.to_wire_bytes(Endianness::Big))
+    }
+
+    /// Write a number to bytes in little endian.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut bytes = vec![0u8; 4];
+    ///
+    /// let unsigned: u32 = 1337;
+    /// Cursor::new(&mut bytes).write_num_le(unsigned).unwrap();
+    /// assert_eq!(bytes, vec![0x39, 0x05, 0x00, 0x00]);
+    ///
+    /// let signed: i32 = -1337;
+    /// Cursor::new(&mut bytes).write_num_le(signed).unwrap();
+    /// assert_eq!(bytes, vec![0xC7, 0xFA, 0xFF, 0xFF]);
+    ///
+    /// let float: f32 = 133.7;
+    /// Cursor::new(&mut bytes).write_num_le(float).unwrap();
+    /// assert_eq!(bytes, vec![0x33, 0xB3, 0x05, 0x43]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_le<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.write_all(&num.to_wire_bytes(Endianness::Little))
+    }
+
+    /// Write a number to bytes in native endianness.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_num_ne<N, const SIZE: usize>(&mut self, num: N) -> Result<()>
+    where
+        N: ToWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.write_all(&num.to_wire_bytes(Endianness::Native))
+    }
+
+    /// Write a slice of numbers in native endianness with a single call to [`Write::write_all`].
+    ///
+    /// The underlying bytes of the slice are written as they are, without serializing each number.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let samples = [0x1337_u16, 0xABCD, 0x0042];
+    /// let mut bytes = Vec::new();
+    /// bytes.write_nums_ne_slice(&samples).unwrap();
+    ///
+    /// let expected: Vec<u8> = samples.iter().flat_map(|sample| sample.to_ne_bytes()).collect();
+    /// assert_eq!(bytes, expected);
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_nums_ne_slice<N>(&mut self, nums: &[N]) -> Result<()>
+    where
+        N: bytemuck::Pod,
+    {
+        self.write_all(bytemuck::cast_slice(nums))
+    }
+
+    /// Write a `rust_decimal::Decimal` as 16 bytes in big endian.
+    ///
+    /// For the layout please refer to [`ReadNumExt::read_decimal_be`].
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_decimal_be(Decimal::new(-12345, 2)).unwrap();
+    /// assert_eq!(bytes[..4], [0x80, 0x02, 0x00, 0x00]);
+    /// assert_eq!(bytes[4..], [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x30, 0x39]);
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_decimal_be(&mut self, decimal: rust_decimal::Decimal) -> Result<()> {
+        let [flags, lo, mid, hi] = decimal_parts(decimal);
+        self.write_all(&flags.to_be_bytes())?;
+        self.write_all(&hi.to_be_bytes())?;
+        self.write_all(&mid.to_be_bytes())?;
+        self.write_all(&lo.to_be_bytes())
+    }
+
+    /// Write a `rust_decimal::Decimal` as 16 bytes in little endian.
+    ///
+    /// For the layout please refer to [`ReadNumExt::read_decimal_le`].
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_decimal_le(Decimal::new(-12345, 2)).unwrap();
+    /// assert_eq!(bytes, Decimal::new(-12345, 2).serialize());
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_decimal_le(&mut self, decimal: rust_decimal::Decimal) -> Result<()> {
+        let [flags, lo, mid, hi] = decimal_parts(decimal);
+        self.write_all(&flags.to_le_bytes())?;
+        self.write_all(&lo.to_le_bytes())?;
+        self.write_all(&mid.to_le_bytes())?;
+        self.write_all(&hi.to_le_bytes())
+    }
+
+    /// Write an unsigned big integer in big endian using the minimal amount of bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_biguint_be(&BigUint::from(65537u32)).unwrap();
+    /// assert_eq!(bytes, [0x01, 0x00, 0x01]);
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_biguint_be(&mut self, num: &num_bigint::BigUint) -> Result<()> {
+        self.write_all(&num.to_bytes_be())
+    }
+
+    /// Write an unsigned big integer in little endian using the minimal amount of bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_biguint_le(&mut self, num: &num_bigint::BigUint) -> Result<()> {
+        self.write_all(&num.to_bytes_le())
+    }
+
+    /// Write a signed big integer in two's complement big endian using the minimal amount of bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bigint_be(&mut self, num: &num_bigint::BigInt) -> Result<()> {
+        self.write_all(&num.to_signed_bytes_be())
+    }
+
+    /// Write a signed big integer in two's complement little endian using the minimal amount of bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_bigint_le(&BigInt::from(-129)).unwrap();
+    /// assert_eq!(bytes, [0x7F, 0xFF]);
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bigint_le(&mut self, num: &num_bigint::BigInt) -> Result<()> {
+        self.write_all(&num.to_signed_bytes_le())
+    }
+
+    /// Write an unsigned big integer in big endian, preceded by its size as `u32` in big endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the size exceeds `u32::MAX`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_biguint_be_prefixed(&BigUint::from(65537u32)).unwrap();
+    /// assert_eq!(bytes, [0x00, 0x00, 0x00, 0x03, 0x01, 0x00, 0x01]);
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_biguint_be_prefixed(&mut self, num: &num_bigint::BigUint) -> Result<()> {
+        write_u32_be_prefixed(self, &num.to_bytes_be())
+    }
+
+    /// Write a signed big integer in two's complement big endian,
+    /// preceded by its size as `u32` in big endian.
+    ///
+    /// This is the `mpint` encoding of RFC 4251.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the size exceeds `u32::MAX`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[cfg(feature = "num-bigint")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bigint_be_prefixed(&mut self, num: &num_bigint::BigInt) -> Result<()> {
+        write_u32_be_prefixed(self, &num.to_signed_bytes_be())
+    }
+
+    /// Write an unsigned integer as `digits` decimal digits in packed BCD.
+    ///
+    /// Each byte holds two digits, most significant digit first.
+    /// For an odd amount of digits, the high nibble of the first byte is zero padding.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `value` has more than `digits` digits.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_bcd(123, 4).unwrap();
+    /// assert_eq!(bytes, [0x01, 0x23]);
+    /// assert!(bytes.write_bcd(123, 2).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bcd(&mut self, mut value: u64, digits: usize) -> Result<()> {
+        let mut bytes = vec![0; digits.div_ceil(2)];
+
+        for index in 0..digits {
+            let digit = u8::try_from(value % 10).expect("digit should fit into u8");
+            value /= 10;
+            let position = digits - 1 - index + digits % 2;
+
+            if position % 2 == 1 {
+                bytes[position / 2] |= digit;
+            } else {
+                bytes[position / 2] |= digit << 4;
+            }
+        }
+
+        if value != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("value does not fit into {digits} BCD digits"),
+            ));
+        }
+
+        self.write_all(&bytes)
+    }
+
+    /// Write an unsigned LEB128 variable-length integer, as used by Protocol Buffers and WebAssembly.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_varint_u64(42).unwrap();
+    /// bytes.write_varint_u64(300).unwrap();
+    /// assert_eq!(bytes, [0x2A, 0xAC, 0x02]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_varint_u64(&mut self, mut value: u64) -> Result<()> {
+        let mut bytes = [0; 10];
+        let mut len = 0;
+
+        loop {
+            let byte = u8::try_from(value & 0x7F).expect("masked value should fit into u8");
+            value >>= 7;
+
+            if value == 0 {
+                bytes[len] = byte;
+                len += 1;
+                break;
+            }
+
+            bytes[len] = byte | 0x80;
+            len += 1;
+        }
+
+        self.write_all(&bytes[..len])
+    }
+
+    /// Write `data` prefixed with its size as unsigned LEB128 variable-length integer.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_bytes_varint_prefixed(&[0xAB, 0xCD, 0xEF]).unwrap();
+    /// assert_eq!(bytes, [0x03, 0xAB, 0xCD, 0xEF]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bytes_varint_prefixed(&mut self, data: &[u8]) -> Result<()> {
+        self.write_varint_u64(data.len() as u64)?;
+        self.write_all(data)
+    }
+
+    /// Write the value of an atomic integer, loaded with the given memory ordering,
+    /// in the given byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteNumExt};
+    /// use std::sync::atomic::{AtomicI16, Ordering};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes
+    ///     .write_atomic(&AtomicI16::new(-2), Ordering::SeqCst, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(bytes, [0xFF, 0xFE]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_atomic<A>(
+        &mut self,
+        atomic: &A,
+        ordering: Ordering,
+        endianness: Endianness,
+    ) -> Result<()>
+    where
+        A: AtomicInteger,
+    {
+        atomic.load_integer(ordering).write_to(self, endianness)
+    }
+
+    /// Write an unsigned integer as exactly `size` bytes in big endian, where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8
+    /// or `value` does not fit into `size` bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_uint_be(0x12_3456, 3).unwrap();
+    /// assert_eq!(bytes, [0x12, 0x34, 0x56]);
+    /// assert!(bytes.write_uint_be(0x100, 1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_uint_be(&mut self, value: u64, size: usize) -> Result<()> {
+        check_uint_fits(value, size)?;
+        self.write_all(&value.to_be_bytes()[8 - size..])
+    }
+
+    /// Write an unsigned integer as exactly `size` bytes in little endian, where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8
+    /// or `value` does not fit into `size` bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_uint_le(0x12_3456, 3).unwrap();
+    /// assert_eq!(bytes, [0x56, 0x34, 0x12]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_uint_le(&mut self, value: u64, size: usize) -> Result<()> {
+        check_uint_fits(value, size)?;
+        self.write_all(&value.to_le_bytes()[..size])
+    }
+
+    /// Write a signed integer as exactly `size` bytes of two's complement in big endian,
+    /// where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8
+    /// or `value` does not fit into `size` bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_int_be(-2, 3).unwrap();
+    /// assert_eq!(bytes, [0xFF, 0xFF, 0xFE]);
+    /// assert!(bytes.write_int_be(128, 1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_int_be(&mut self, value: i64, size: usize) -> Result<()> {
+        check_int_fits(value, size)?;
+        self.write_all(&value.to_be_bytes()[8 - size..])
+    }
+
+    /// Write a signed integer as exactly `size` bytes of two's complement in little endian,
+    /// where `size` is at most 8.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `size` exceeds 8
+    /// or `value` does not fit into `size` bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_int_le(-129, 2).unwrap();
+    /// assert_eq!(bytes, [0x7F, 0xFF]);
+    /// assert!(bytes.write_int_le(-129, 1).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_int_le(&mut self, value: i64, size: usize) -> Result<()> {
+        check_int_fits(value, size)?;
+        self.write_all(&value.to_le_bytes()[..size])
+    }
+
+    /// Convert a `usize` into an unsigned integer of type `P` and write it in the given byte order.
+    ///
+    /// The width of the field on the wire is given by `P`, independently of the target platform.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the value does not fit into `P`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteNumExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_usize::<u32>(256, Endianness::Big).unwrap();
+    /// assert_eq!(bytes, [0x00, 0x00, 0x01, 0x00]);
+    /// assert!(Vec::new().write_usize::<u8>(256, Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_usize<P>(&mut self, value: usize, endianness: Endianness) -> Result<()>
+    where
+        P: WriteTo + TryFrom<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        P::try_from(value)
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+            .write_to(self, endianness)
+    }
+
+    /// Convert an `isize` into a signed integer of type `P` and write it in the given byte order.
+    ///
+    /// The width of the field on the wire is given by `P`, independently of the target platform.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the value does not fit into `P`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteNumExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_isize::<i16>(-2, Endianness::Little).unwrap();
+    /// assert_eq!(bytes, [0xFE, 0xFF]);
+    /// assert!(Vec::new().write_isize::<i8>(-129, Endianness::Little).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_isize<P>(&mut self, value: isize, endianness: Endianness) -> Result<()>
+    where
+        P: WriteTo + TryFrom<isize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        P::try_from(value)
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+            .write_to(self, endianness)
+    }
+
+    /// Write a length as `u16` in big endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u16_be(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u16>(len, Endianness::Big)
+    }
+
+    /// Write a length as `u16` in little endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u16_le(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u16>(len, Endianness::Little)
+    }
+
+    /// Write a length as `u32` in big endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_len_u32_be(3).unwrap();
+    /// assert_eq!(bytes, [0x00, 0x00, 0x00, 0x03]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u32_be(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u32>(len, Endianness::Big)
+    }
+
+    /// Write a length as `u32` in little endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u32_le(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u32>(len, Endianness::Little)
+    }
+
+    /// Write a length as `u64` in big endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u64_be(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u64>(len, Endianness::Big)
+    }
+
+    /// Write a length as `u64` in little endian.
+    ///
+    /// For further semantics please refer to [`WriteNumExt::write_usize`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_len_u64_le(&mut self, len: usize) -> Result<()> {
+        self.write_usize::<u64>(len, Endianness::Little)
+    }
+
+    /// Write `bool`s packed into `bools.len().div_ceil(8)` bytes with the most significant bit first.
+    ///
+    /// Padding bits after the last value up to the next byte boundary are zero.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes
+    ///     .write_bool_array_packed_be(&[true, false, true, false, false, false, false, false, false, true])
+    ///     .unwrap();
+    /// assert_eq!(bytes, [0b1010_0000, 0b0100_0000]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bool_array_packed_be(&mut self, bools: &[bool]) -> Result<()> {
+        self.write_all(&pack_bools(bools, true))
+    }
+
+    /// Write `bool`s packed into `bools.len().div_ceil(8)` bytes with the least significant bit first.
+    ///
+    /// Padding bits after the last value up to the next byte boundary are zero.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_bool_array_packed_le(&[true, false, true, false]).unwrap();
+    /// assert_eq!(bytes, [0b0000_0101]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_bool_array_packed_le(&mut self, bools: &[bool]) -> Result<()> {
+        self.write_all(&pack_bools(bools, false))
+    }
+
+    /// Write a signed integer as ZigZag-encoded LEB128 variable-length integer,
+    /// as used by Protocol Buffers' `sint64`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteNumExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_varint_i64(-1).unwrap();
+    /// bytes.write_varint_i64(1).unwrap();
+    /// bytes.write_varint_i64(i64::MIN).unwrap();
+    /// assert_eq!(bytes[..2], [0x01, 0x02]);
+    /// assert_eq!(bytes[2..], [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_varint_i64(&mut self, value: i64) -> Result<()> {
+        self.write_varint_u64(((value << 1) ^ (value >> 63)).cast_unsigned())
+    }
+
+    /// Write a Protocol Buffers field key of the given field number and wire type.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the field number is not within
+    /// `1..=536_870_911`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{WireType, WriteNumExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_protobuf_key(18, WireType::Len).unwrap();
+    /// assert_eq!(bytes, [0x92, 0x01]);
+    /// assert!(bytes.write_protobuf_key(0, WireType::Varint).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_protobuf_key(&mut self, field_number: u32, wire_type: WireType) -> Result<()> {
+        if !(1..=MAX_FIELD_NUMBER).contains(&field_number) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid protobuf field number: {field_number}"),
+            ));
+        }
+
+        self.write_varint_u64(u64::from(field_number) << 3 | wire_type as u64)
+    }
+}
+
+impl<T> WriteNumExt for T where T: Write {}
+
+fn read_packed_uints<R>(
+    reader: &mut R,
+    count: usize,
+    bits: u32,
+    msb_first: bool,
+) -> Result<Vec<u32>>
+where
+    R: Read + ?Sized,
+{
+    if !(1..=32).contains(&bits) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid bit width: {bits}"),
+        ));
+    }
+
+    let size = count
+        .checked_mul(bits as usize)
+        .map(|total_bits| total_bits.div_ceil(8))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "too many packed integers"))?;
+    let mut bytes = Vec::new();
+    fill_vec(reader, &mut bytes, size)?;
+
+    let mut values = Vec::with_capacity(count);
+
+    // Groups of whole bytes holding whole values are unpacked from one word each.
+    match bits {
+        10 => unpack_groups::<5, 4>(&bytes, count, bits, msb_first, &mut values),
+        12 => unpack_groups::<3, 2>(&bytes, count, bits, msb_first, &mut values),
+        _ => {}
+    }
+
+    // Pad the bytes, so that a whole word can be loaded at the start of every value.
+    bytes.resize(size + 7, 0);
+    let mask = u64::MAX >> (64 - bits);
+
+    for index in values.len()..count {
+        let offset = index * bits as usize;
+        let word: [u8; 8] = bytes[offset / 8..][..8]
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("the bytes should be padded to whole words"));
+        // `offset % 8 + bits` does not exceed 39, so the value lies within the word.
+        let shift = offset % 8;
+        let value = if msb_first {
+            u64::from_be_bytes(word) >> (64 - shift - bits as usize)
+        } else {
+            u64::from_le_bytes(word) >> shift
+        };
+        values.push(truncate_to_u32(value & mask));
+    }
+
+    Ok(values)
+}
+
+/// Unpack the `VALUES` values of `bits` bits contained in each complete group of `GROUP` bytes
+/// of `bytes` into `values`.
+fn unpack_groups<const GROUP: usize, const VALUES: u32>(
+    bytes: &[u8],
+    count: usize,
+    bits: u32,
+    msb_first: bool,
+    values: &mut Vec<u32>,
+) {
+    let mask = u64::MAX >> (64 - bits);
+
+    for group in bytes.chunks_exact(GROUP).take(count / VALUES as usize) {
+        let mut word = [0; 8];
+
+        if msb_first {
+            word[8 - GROUP..].copy_from_slice(group);
+            let word = u64::from_be_bytes(word);
+            values.extend(
+                (0..VALUES)
+                    .rev()
+                    .map(|index| truncate_to_u32((word >> (index * bits)) & mask)),
+            );
+        } else {
+            word[..GROUP].copy_from_slice(group);
+            let word = u64::from_le_bytes(word);
+            values
+                .extend((0..VALUES).map(|index| truncate_to_u32((word >> (index * bits)) & mask)));
+        }
+    }
+}
+
+/// Return the lower 32 bits of `value`.
+#[allow(clippy::cast_possible_truncation)]
+const fn truncate_to_u32(value: u64) -> u32 {
+    value as u32
+}
+
+fn read_bool_array_packed<R, const SIZE: usize>(
+    reader: &mut R,
+    msb_first: bool,
+) -> Result<[bool; SIZE]>
+where
+    R: Read + ?Sized,
+{
+    let mut bytes = Vec::new();
+    fill_vec(reader, &mut bytes, SIZE.div_ceil(8))?;
+    Ok(std::array::from_fn(|index| {
+        let shift = if msb_first { 7 - index % 8 } else { index % 8 };
+        bytes[index / 8] >> shift & 1 != 0
+    }))
+}
+
+fn pack_bools(bools: &[bool], msb_first: bool) -> Vec<u8> {
+    bools
+        .chunks(8)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(0, |byte, (index, &bit)| {
+                let shift = if msb_first { 7 - index } else { index };
+                byte | u8::from(bit) << shift
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "rust_decimal")]
+fn decimal_from_parts(flags: u32, lo: u32, mid: u32, hi: u32) -> Result<rust_decimal::Decimal> {
+    const SIGN_MASK: u32 = 0x8000_0000;
+    const SCALE_MASK: u32 = 0x00FF_0000;
+
+    let scale = (flags & SCALE_MASK) >> 16;
+
+    if flags & !(SIGN_MASK | SCALE_MASK) != 0 || scale > rust_decimal::Decimal::MAX_SCALE {
+        return Err(Error::new(ErrorKind::InvalidData, "invalid decimal flags"));
+    }
+
+    Ok(rust_decimal::Decimal::from_parts(
+        lo,
+        mid,
+        hi,
+        flags & SIGN_MASK != 0,
+        scale,
+    ))
+}
+
+/// Return the flags, low, middle and high words of a decimal.
+#[cfg(feature = "rust_decimal")]
+fn decimal_parts(decimal: rust_decimal::Decimal) -> [u32; 4] {
+    let bytes = decimal.serialize();
+    [0, 4, 8, 12].map(|offset| {
+        u32::from_le_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    })
+}
+
+#[cfg(feature = "num-bigint")]
+fn write_u32_be_prefixed<W>(writer: &mut W, bytes: &[u8]) -> Result<()>
+where
+    W: Write + ?Sized,
+{
+    let size =
+        u32::try_from(bytes.len()).map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+    writer.write_all(&size.to_be_bytes())?;
+    writer.write_all(bytes)
+}
+
+/// Return `size` if it is a valid size of a runtime-width integer.
+fn check_int_size(size: usize) -> Result<usize> {
+    if size > 8 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("integer size {size} exceeds 8 bytes"),
+        ));
+    }
+
+    Ok(size)
+}
+
+fn check_uint_fits(value: u64, size: usize) -> Result<()> {
+    let bits = 8 * check_int_size(size)?;
+
+    if bits < 64 && value >> bits != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("value {value} does not fit into {size} bytes"),
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_int_fits(value: i64, size: usize) -> Result<()> {
+    let bits = 8 * check_int_size(size)?;
+
+    if bits < 64 && sign_extend(value.cast_unsigned() & ((1 << bits) - 1), size) != value {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("value {value} does not fit into {size} bytes"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Interpret the lower `size` bytes of `value` as two's complement integer.
+fn sign_extend(value: u64, size: usize) -> i64 {
+    let unused = 64 - 8 * size;
+
+    if unused == 64 {
+        return 0;
+    }
+
+    (value << unused).cast_signed() >> unused
+}
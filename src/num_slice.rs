@@ -0,0 +1,211 @@
+use std::fmt::{self, Debug, Formatter};
+use std::io::{Error, ErrorKind, Result};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::{ByteOrder, FromWireBytes};
+
+/// A view over a byte slice of numbers of type `N` in the byte order `E`.
+///
+/// Unlike reading the numbers into a `Vec<N>`, the numbers are only decoded when they are accessed,
+/// which is cheap for large tables, e.g. in memory-mapped files, of which only a few entries are used.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{BigEndian, NumSlice};
+///
+/// let bytes = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+/// let nums = NumSlice::<u16, BigEndian>::new(&bytes).unwrap();
+/// assert_eq!(nums.len(), 3);
+/// assert_eq!(nums.get(1), Some(2));
+/// assert_eq!(nums.get(3), None);
+/// assert_eq!(nums.iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+///
+/// assert!(NumSlice::<u16, BigEndian>::new(&bytes[..5]).is_err());
+/// ```
+pub struct NumSlice<'a, N, E> {
+    bytes: &'a [u8],
+    marker: PhantomData<fn() -> (N, E)>,
+}
+
+impl<'a, N, E, const SIZE: usize> NumSlice<'a, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    /// Create a new view over `bytes`.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the length of `bytes`
+    /// is not a multiple of the size of `N`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if !bytes.len().is_multiple_of(SIZE) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} bytes are not a multiple of {SIZE}", bytes.len()),
+            ));
+        }
+
+        Ok(Self {
+            bytes,
+            marker: PhantomData,
+        })
+    }
+
+    /// Return the amount of numbers.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / SIZE
+    }
+
+    /// Return `true` if the view contains no numbers.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Decode the number at `index`, or return `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<N> {
+        let start = index.checked_mul(SIZE)?;
+        self.bytes
+            .get(start..start.checked_add(SIZE)?)
+            .map(|chunk| decode::<N, E, SIZE>(chunk))
+    }
+
+    /// Return an iterator decoding the numbers in order.
+    #[must_use]
+    pub fn iter(&self) -> NumSliceIter<'a, N, E> {
+        NumSliceIter {
+            chunks: self.bytes.chunks_exact(SIZE),
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the underlying bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<N, E> Clone for NumSlice<'_, N, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, E> Copy for NumSlice<'_, N, E> {}
+
+impl<N, E> Debug for NumSlice<'_, N, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumSlice")
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl<'a, N, E, const SIZE: usize> IntoIterator for NumSlice<'a, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    type Item = N;
+    type IntoIter = NumSliceIter<'a, N, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, N, E, const SIZE: usize> IntoIterator for &NumSlice<'a, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    type Item = N;
+    type IntoIter = NumSliceIter<'a, N, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator decoding the numbers of a [`NumSlice`].
+pub struct NumSliceIter<'a, N, E> {
+    chunks: std::slice::ChunksExact<'a, u8>,
+    marker: PhantomData<fn() -> (N, E)>,
+}
+
+impl<N, E> Clone for NumSliceIter<'_, N, E> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<N, E> Debug for NumSliceIter<'_, N, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumSliceIter")
+            .field("chunks", &self.chunks)
+            .finish()
+    }
+}
+
+impl<N, E, const SIZE: usize> Iterator for NumSliceIter<'_, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(decode::<N, E, SIZE>)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n).map(decode::<N, E, SIZE>)
+    }
+}
+
+impl<N, E, const SIZE: usize> DoubleEndedIterator for NumSliceIter<'_, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(decode::<N, E, SIZE>)
+    }
+}
+
+impl<N, E, const SIZE: usize> ExactSizeIterator for NumSliceIter<'_, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+}
+
+impl<N, E, const SIZE: usize> FusedIterator for NumSliceIter<'_, N, E>
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+}
+
+/// Decode a number from a chunk of exactly `SIZE` bytes.
+fn decode<N, E, const SIZE: usize>(chunk: &[u8]) -> N
+where
+    N: FromWireBytes<Bytes = [u8; SIZE]>,
+    E: ByteOrder,
+{
+    let bytes = chunk
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("chunks should have exactly {SIZE} bytes"));
+    N::from_wire_bytes(bytes, E::ENDIANNESS)
+}
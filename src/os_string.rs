@@ -0,0 +1,103 @@
+//! Portable wire encoding of OS strings.
+//!
+//! An OS string is encoded as a tag byte, a `u32` payload size in big endian, and the payload.
+//! The tag denotes the payload encoding:
+//!
+//! * [`UTF8`]: UTF-8, which can be decoded on every platform.
+//! * [`UNIX`]: arbitrary bytes, which can only be decoded on Unix.
+//! * [`WINDOWS`]: potentially ill-formed UTF-16 in little endian, which can only be decoded on Windows.
+//!
+//! Strings are encoded as UTF-8 whenever possible.
+
+use std::ffi::{OsStr, OsString};
+use std::io::{Error, ErrorKind, Result};
+
+const UTF8: u8 = 0;
+const UNIX: u8 = 1;
+const WINDOWS: u8 = 2;
+
+/// Return the tag and payload encoding the given OS string.
+#[cfg_attr(any(unix, windows), allow(clippy::unnecessary_wraps))]
+pub(crate) fn encode(string: &OsStr) -> Result<(u8, Vec<u8>)> {
+    if let Some(string) = string.to_str() {
+        return Ok((UTF8, string.as_bytes().to_vec()));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        Ok((UNIX, string.as_bytes().to_vec()))
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        Ok((
+            WINDOWS,
+            string.encode_wide().flat_map(u16::to_le_bytes).collect(),
+        ))
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    Err(Error::new(
+        ErrorKind::InvalidInput,
+        "OS string is not valid UTF-8",
+    ))
+}
+
+/// Decode the OS string from the given tag and payload.
+pub(crate) fn decode(tag: u8, payload: Vec<u8>) -> Result<OsString> {
+    match tag {
+        UTF8 => String::from_utf8(payload)
+            .map(OsString::from)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+        UNIX => decode_unix(payload),
+        WINDOWS => decode_windows(&payload),
+        tag => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid OS string tag: {tag}"),
+        )),
+    }
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)]
+fn decode_unix(payload: Vec<u8>) -> Result<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+    Ok(OsString::from_vec(payload))
+}
+
+#[cfg(not(unix))]
+fn decode_unix(_payload: Vec<u8>) -> Result<OsString> {
+    Err(unsupported())
+}
+
+#[cfg(windows)]
+fn decode_windows(payload: &[u8]) -> Result<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+
+    if payload.len() % 2 != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "odd amount of bytes in UTF-16 OS string",
+        ));
+    }
+
+    let wide: Vec<u16> = payload
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect();
+    Ok(OsString::from_wide(&wide))
+}
+
+#[cfg(not(windows))]
+fn decode_windows(_payload: &[u8]) -> Result<OsString> {
+    Err(unsupported())
+}
+
+fn unsupported() -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        "OS string encoding not supported on this platform",
+    )
+}
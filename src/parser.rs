@@ -0,0 +1,182 @@
+use std::io::{Read, Result};
+
+use crate::{Endianness, ReadBytesExt, ReadFrom};
+
+/// Tuples which can be extended by one element.
+///
+/// Implemented for tuples of up to 11 elements.
+pub trait Append<U> {
+    /// The tuple with `U` appended.
+    type Output;
+
+    /// Append `value` to the tuple.
+    fn append(self, value: U) -> Self::Output;
+}
+
+macro_rules! impl_append {
+    ($($name:ident),*) => {
+        impl<$($name,)* U> Append<U> for ($($name,)*) {
+            type Output = ($($name,)* U,);
+
+            #[allow(non_snake_case)]
+            fn append(self, value: U) -> Self::Output {
+                let ($($name,)*) = self;
+                ($($name,)* value,)
+            }
+        }
+    };
+}
+
+impl_append!();
+impl_append!(A);
+impl_append!(A, B);
+impl_append!(A, B, C);
+impl_append!(A, B, C, D);
+impl_append!(A, B, C, D, E);
+impl_append!(A, B, C, D, E, F);
+impl_append!(A, B, C, D, E, F, G);
+impl_append!(A, B, C, D, E, F, G, H);
+impl_append!(A, B, C, D, E, F, G, H, I);
+impl_append!(A, B, C, D, E, F, G, H, I, J);
+impl_append!(A, B, C, D, E, F, G, H, I, J, K);
+
+/// A fluent parser collecting the values read from a stream into a tuple.
+///
+/// See [`ReadBytesExt::parse`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::ReadBytesExt;
+/// use std::io::Cursor;
+///
+/// let bytes = *b"\x00\x00\x01\x00OK\x02\x00\xAB\xCD";
+/// let (size, id, payload) = Cursor::new(&bytes)
+///     .parse()
+///     .be::<u32>()?
+///     .tag(b"OK")?
+///     .le::<u16>()?
+///     .bytes(2)?
+///     .finish();
+/// assert_eq!(size, 0x0100);
+/// assert_eq!(id, 2);
+/// assert_eq!(payload, [0xAB, 0xCD]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Parser<'a, R, T>
+where
+    R: ?Sized,
+{
+    reader: &'a mut R,
+    values: T,
+}
+
+impl<'a, R> Parser<'a, R, ()>
+where
+    R: ?Sized,
+{
+    pub(crate) fn new(reader: &'a mut R) -> Self {
+        Self { reader, values: () }
+    }
+}
+
+impl<'a, R, T> Parser<'a, R, T>
+where
+    R: Read + ?Sized,
+{
+    /// Read a value of a type implementing [`ReadFrom`] in the given byte order and collect it.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn value<U>(self, endianness: Endianness) -> Result<Parser<'a, R, T::Output>>
+    where
+        T: Append<U>,
+        U: ReadFrom,
+    {
+        let value = U::read_from(self.reader, endianness)?;
+        Ok(self.push(value))
+    }
+
+    /// Read a value in big endian and collect it.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn be<U>(self) -> Result<Parser<'a, R, T::Output>>
+    where
+        T: Append<U>,
+        U: ReadFrom,
+    {
+        self.value(Endianness::Big)
+    }
+
+    /// Read a value in little endian and collect it.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn le<U>(self) -> Result<Parser<'a, R, T::Output>>
+    where
+        T: Append<U>,
+        U: ReadFrom,
+    {
+        self.value(Endianness::Little)
+    }
+
+    /// Read a byte array of a constant size and collect it.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn array<const SIZE: usize>(self) -> Result<Parser<'a, R, T::Output>>
+    where
+        T: Append<[u8; SIZE]>,
+    {
+        let mut array = [0; SIZE];
+        self.reader.read_exact(&mut array)?;
+        Ok(self.push(array))
+    }
+
+    /// Read a `Vec<u8>` of a given size and collect it.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn bytes(mut self, size: usize) -> Result<Parser<'a, R, T::Output>>
+    where
+        T: Append<Vec<u8>>,
+    {
+        let bytes = (&mut self.reader).read_vec_exact(size)?;
+        Ok(self.push(bytes))
+    }
+
+    /// Read `magic.len()` bytes and verify that they equal `magic`, without collecting them.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader,
+    /// or an error of kind [`ErrorKind::InvalidData`](std::io::ErrorKind::InvalidData) if the read bytes differ.
+    pub fn tag(mut self, magic: &[u8]) -> Result<Self> {
+        (&mut self.reader).read_magic(magic)?;
+        Ok(self)
+    }
+
+    /// Skip `size` bytes without collecting them.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader.
+    pub fn skip(mut self, size: u64) -> Result<Self> {
+        (&mut self.reader).copy_exact(&mut std::io::sink(), size)?;
+        Ok(self)
+    }
+
+    /// Return the collected values.
+    pub fn finish(self) -> T {
+        self.values
+    }
+
+    fn push<U>(self, value: U) -> Parser<'a, R, T::Output>
+    where
+        T: Append<U>,
+    {
+        Parser {
+            reader: self.reader,
+            values: self.values.append(value),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::io::{Error, ErrorKind, Read, Result};
+#[cfg(feature = "futures-io")]
+use std::pin::Pin;
+#[cfg(feature = "futures-io")]
+use std::task::{Context, Poll};
+
+/// An exact read that can be resumed across multiple attempts.
+///
+/// This is intended for non-blocking sources, which return [`ErrorKind::WouldBlock`]
+/// when no data is available, and would therefore lose partial progress in [`Read::read_exact`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::PartialRead;
+/// use std::io::{ErrorKind, Read, Result};
+///
+/// /// A reader that yields one byte per call and blocks in between.
+/// struct Trickle<'a>(&'a [u8], bool);
+///
+/// impl Read for Trickle<'_> {
+///     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+///         self.1 = !self.1;
+///
+///         if self.1 {
+///             return Err(ErrorKind::WouldBlock.into());
+///         }
+///
+///         Read::take(&mut self.0, 1).read(buf)
+///     }
+/// }
+///
+/// let mut reader = Trickle(&[0xAB, 0xCD, 0xEF, 0x42], false);
+/// let mut partial = PartialRead::<[u8; 3]>::array();
+///
+/// while !partial.read_from(&mut reader).unwrap() {
+///     // Wait for the source to become readable again.
+/// }
+///
+/// assert_eq!(partial.into_inner(), [0xAB, 0xCD, 0xEF]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PartialRead<B> {
+    buffer: B,
+    filled: usize,
+}
+
+impl<B> PartialRead<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Create a new partial read that fills the given buffer.
+    #[must_use]
+    pub const fn new(buffer: B) -> Self {
+        Self { buffer, filled: 0 }
+    }
+
+    /// Return the amount of bytes read so far.
+    #[must_use]
+    pub const fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Return `true` if the buffer has been filled completely.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.filled == self.buffer.as_ref().len()
+    }
+
+    /// Read from `reader` until the buffer is full or `reader` would block.
+    ///
+    /// Returns `Ok(true)` if the buffer has been filled completely,
+    /// or `Ok(false)` if `reader` returned an error of kind [`ErrorKind::WouldBlock`].
+    /// Reads interrupted by [`ErrorKind::Interrupted`] are retried.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if `reader` reaches its end
+    /// before the buffer is full, or any other error returned by `reader`.
+    pub fn read_from<R>(&mut self, reader: &mut R) -> Result<bool>
+    where
+        R: Read + ?Sized,
+    {
+        let buffer = self.buffer.as_mut();
+
+        while self.filled < buffer.len() {
+            match reader.read(&mut buffer[self.filled..]) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => self.filled += n,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) if error.kind() == ErrorKind::WouldBlock => return Ok(false),
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Attempt to read from the asynchronous `reader` until the buffer is full.
+    ///
+    /// Returns [`Poll::Ready`] once the buffer has been filled completely.
+    /// Since the read progress is stored in `self` rather than in a future,
+    /// no data is lost if the surrounding future is dropped, e.g. in a `select!`.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if `reader` reaches its end
+    /// before the buffer is full, or any other error returned by `reader`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::PartialRead;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut reader: &[u8] = &[0xAB, 0xCD, 0xEF, 0x42];
+    /// let mut partial = PartialRead::<[u8; 4]>::array();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert!(matches!(
+    ///     partial.poll_read_from(&mut cx, Pin::new(&mut reader)),
+    ///     Poll::Ready(Ok(()))
+    /// ));
+    /// assert_eq!(partial.into_inner(), [0xAB, 0xCD, 0xEF, 0x42]);
+    /// ```
+    #[cfg(feature = "futures-io")]
+    pub fn poll_read_from<R>(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut reader: Pin<&mut R>,
+    ) -> Poll<Result<()>>
+    where
+        R: futures_io::AsyncRead + ?Sized,
+    {
+        let buffer = self.buffer.as_mut();
+
+        while self.filled < buffer.len() {
+            match reader.as_mut().poll_read(cx, &mut buffer[self.filled..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    )))
+                }
+                Poll::Ready(Ok(n)) => self.filled += n,
+                Poll::Ready(Err(error)) if error.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Return the underlying buffer.
+    ///
+    /// Only the first [`filled`](Self::filled) bytes have been read.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<const SIZE: usize> PartialRead<[u8; SIZE]> {
+    /// Create a new partial read of a byte array of a constant size.
+    #[must_use]
+    pub const fn array() -> Self {
+        Self::new([0; SIZE])
+    }
+}
+
+impl PartialRead<Vec<u8>> {
+    /// Create a new partial read of a `Vec<u8>` of a given size.
+    #[must_use]
+    pub fn vec(size: usize) -> Self {
+        Self::new(vec![0; size])
+    }
+}
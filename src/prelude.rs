@@ -0,0 +1,26 @@
+//! Re-exports of all extension traits.
+//!
+//! Import everything at once with `use rw_exact_ext::prelude::*;`,
+//! or import the individual traits from the crate root to use only parts of the API.
+//!
+//! # Examples
+//! ```
+//! use rw_exact_ext::prelude::*;
+//! use std::io::Cursor;
+//! use std::net::Ipv4Addr;
+//!
+//! let mut cursor = Cursor::new([0x01, 0x7F, 0x00, 0x00, 0x01]);
+//! assert!(cursor.read_bool().unwrap());
+//! assert_eq!(cursor.read_ipv4_addr().unwrap(), Ipv4Addr::LOCALHOST);
+//! ```
+
+pub use crate::{
+    BufReadExactExt, DatagramExt, DeadlineReadExt, EncodedLen, ReadBytesExt, ReadDynExt, ReadFrom,
+    ReadNetExt, ReadNumExt, ReadStrExt, ReadTimeExt, ReadValueExt, WriteBytesExt, WriteDynExt,
+    WriteNetExt, WriteNumExt, WriteStrExt, WriteTimeExt, WriteTo, WriteValueExt,
+};
+
+#[cfg(feature = "futures-io")]
+pub use crate::AsyncReadValueExt;
+#[cfg(any(unix, windows))]
+pub use crate::FileExactExt;
@@ -0,0 +1,64 @@
+use std::io::{Error, ErrorKind, Result};
+
+/// Largest field number allowed by Protocol Buffers.
+pub(crate) const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+
+/// Wire types of Protocol Buffers fields.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{ReadNumExt, WireType, WriteNumExt};
+/// use std::io::Cursor;
+///
+/// // Unknown field 2 containing a string, followed by field 1 containing the varint 150.
+/// let mut message = Vec::new();
+/// message.write_protobuf_key(2, WireType::Len).unwrap();
+/// message.write_bytes_varint_prefixed(b"unknown").unwrap();
+/// message.write_protobuf_key(1, WireType::Varint).unwrap();
+/// message.write_varint_u64(150).unwrap();
+///
+/// let mut cursor = Cursor::new(&message);
+/// let mut id = None;
+///
+/// while cursor.position() < message.len() as u64 {
+///     match cursor.read_protobuf_key().unwrap() {
+///         (1, WireType::Varint) => id = Some(cursor.read_varint_u64().unwrap()),
+///         (field_number, wire_type) => cursor.skip_protobuf_field(field_number, wire_type).unwrap(),
+///     }
+/// }
+///
+/// assert_eq!(id, Some(150));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum WireType {
+    /// Variable-length integer.
+    Varint = 0,
+    /// Fixed 64-bit value.
+    I64 = 1,
+    /// Length-delimited bytes, such as strings, nested messages and packed repeated fields.
+    Len = 2,
+    /// Start of a deprecated group.
+    SGroup = 3,
+    /// End of a deprecated group.
+    EGroup = 4,
+    /// Fixed 32-bit value.
+    I32 = 5,
+}
+
+impl WireType {
+    /// Return the wire type encoded in the lower three bits of a field key.
+    pub(crate) fn from_key(key: u64) -> Result<Self> {
+        match key & 0b111 {
+            0 => Ok(Self::Varint),
+            1 => Ok(Self::I64),
+            2 => Ok(Self::Len),
+            3 => Ok(Self::SGroup),
+            4 => Ok(Self::EGroup),
+            5 => Ok(Self::I32),
+            wire_type => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid protobuf wire type: {wire_type}"),
+            )),
+        }
+    }
+}
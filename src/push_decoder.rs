@@ -0,0 +1,153 @@
+use std::io::{ErrorKind, Result};
+
+use crate::{Endianness, ReadFrom};
+
+/// A push-based decoder, which buffers the byte chunks fed to it and yields values once they are complete.
+///
+/// Decoding is independent of [`Read`](std::io::Read): the bytes can come from event loops,
+/// message callbacks or DMA buffers alike.
+/// A value is decoded by running the decoding logic against the buffered bytes.
+/// If it runs out of bytes, i.e. fails with [`ErrorKind::UnexpectedEof`], the value is incomplete
+/// and the bytes stay buffered until more are pushed.
+/// Since decoding logic cannot be resumed, an incomplete value is decoded from its start again
+/// on the next poll, so values should be polled after pushing new bytes rather than in a loop.
+///
+/// Decoded bytes are not removed from the buffer one value at a time. Instead, the buffer is
+/// compacted once the decoded bytes make up more than half of it, so that decoding many small
+/// values from one large chunk takes linear time.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, PushDecoder, ReadNumExt};
+///
+/// let mut decoder = PushDecoder::new(Endianness::Big);
+/// decoder.push(&[0x00, 0x01, 0x00]);
+/// assert_eq!(decoder.poll::<u16>().unwrap(), Some(1));
+/// assert_eq!(decoder.poll::<u16>().unwrap(), None);
+///
+/// decoder.push(&[0x02, 0x03, b'a']);
+/// assert_eq!(decoder.poll::<u16>().unwrap(), Some(2));
+///
+/// // Arbitrary decoding logic, here a varint-prefixed frame.
+/// let frame = |bytes: &mut &[u8]| bytes.read_vec_varint_prefixed(Some(1024));
+/// assert_eq!(decoder.poll_with(frame).unwrap(), None);
+/// decoder.push(b"bc");
+/// assert_eq!(decoder.poll_with(frame).unwrap(), Some(b"abc".to_vec()));
+/// assert!(decoder.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PushDecoder {
+    buffer: Vec<u8>,
+    position: usize,
+    endianness: Endianness,
+}
+
+impl PushDecoder {
+    /// Create a new decoder, decoding numbers in the given byte order.
+    #[must_use]
+    pub const fn new(endianness: Endianness) -> Self {
+        Self {
+            buffer: Vec::new(),
+            position: 0,
+            endianness,
+        }
+    }
+
+    /// Append a chunk of bytes to the buffer.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decode a value of a type implementing [`ReadFrom`] from the buffered bytes.
+    ///
+    /// Returns `Ok(None)` if the buffered bytes do not contain a complete value yet.
+    ///
+    /// # Errors
+    /// Returns any error of decoding other than [`ErrorKind::UnexpectedEof`].
+    /// The buffered bytes are kept in that case.
+    pub fn poll<T>(&mut self) -> Result<Option<T>>
+    where
+        T: ReadFrom,
+    {
+        let endianness = self.endianness;
+        self.poll_with(|bytes| T::read_from(bytes, endianness))
+    }
+
+    /// Decode a value from the buffered bytes with the given function.
+    ///
+    /// `decode` reads from the buffered bytes and may be called repeatedly on the same bytes
+    /// until enough of them have been pushed.
+    /// Returns `Ok(None)` if `decode` fails with [`ErrorKind::UnexpectedEof`].
+    /// Otherwise, the bytes read by `decode` are removed from the buffer.
+    ///
+    /// # Errors
+    /// Returns any error of `decode` other than [`ErrorKind::UnexpectedEof`].
+    /// The buffered bytes are kept in that case.
+    pub fn poll_with<F, T>(&mut self, decode: F) -> Result<Option<T>>
+    where
+        F: FnOnce(&mut &[u8]) -> Result<T>,
+    {
+        decode_prefix(self.buffered(), decode).map(|decoded| {
+            decoded.map(|(value, consumed)| {
+                self.consume(consumed);
+                value
+            })
+        })
+    }
+
+    /// Return the buffered bytes which have not been decoded yet.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[self.position..]
+    }
+
+    /// Return the amount of buffered bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Return `true` if no bytes are buffered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Mark `amount` buffered bytes as decoded and compact the buffer
+    /// once the decoded bytes make up more than half of it.
+    fn consume(&mut self, amount: usize) {
+        self.position += amount;
+
+        if self.position == self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+        } else if self.position > self.buffer.len() / 2 {
+            self.buffer.drain(..self.position);
+            self.position = 0;
+        }
+    }
+}
+
+impl PartialEq for PushDecoder {
+    fn eq(&self, other: &Self) -> bool {
+        self.buffered() == other.buffered() && self.endianness == other.endianness
+    }
+}
+
+impl Eq for PushDecoder {}
+
+/// Decode a value from the start of `bytes` and return it with the amount of bytes it occupies.
+///
+/// Returns `Ok(None)` if `decode` fails with [`ErrorKind::UnexpectedEof`].
+pub(crate) fn decode_prefix<F, T>(bytes: &[u8], decode: F) -> Result<Option<(T, usize)>>
+where
+    F: FnOnce(&mut &[u8]) -> Result<T>,
+{
+    let mut remaining = bytes;
+
+    match decode(&mut remaining) {
+        Ok(value) => Ok(Some((value, bytes.len() - remaining.len()))),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{Read, Result};
+use std::marker::PhantomData;
+
+use crate::{fill_or_eof, Endianness, ReadFrom};
+
+/// An iterator reading values until the end of the underlying stream.
+///
+/// See [`ReadValueExt::read_values_to_end`](crate::ReadValueExt::read_values_to_end).
+#[derive(Debug)]
+pub struct ReadValues<'a, R, T>
+where
+    R: ?Sized,
+{
+    reader: &'a mut R,
+    endianness: Endianness,
+    done: bool,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<'a, R, T> ReadValues<'a, R, T>
+where
+    R: ?Sized,
+{
+    pub(crate) fn new(reader: &'a mut R, endianness: Endianness) -> Self {
+        Self {
+            reader,
+            endianness,
+            done: false,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<R, T> Iterator for ReadValues<'_, R, T>
+where
+    R: Read + ?Sized,
+    T: ReadFrom,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut first = [0];
+        let result = match fill_or_eof(self.reader, &mut first) {
+            Ok(false) => {
+                self.done = true;
+                return None;
+            }
+            Ok(true) => T::read_from(
+                &mut first.as_slice().chain(&mut *self.reader),
+                self.endianness,
+            ),
+            Err(error) => Err(error),
+        };
+
+        self.done = result.is_err();
+        Some(result)
+    }
+}
@@ -0,0 +1,167 @@
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+
+use crate::{Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+/// Random access to a flat table of fixed-size records.
+///
+/// Record `index` is stored at offset `index * T::ENCODED_LEN` of the underlying storage.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, RecordFile};
+/// use std::io::Cursor;
+///
+/// let mut table = RecordFile::<(u16, u8), _>::new(Cursor::new(Vec::new()), Endianness::Big);
+/// table.set(0, &(0x1234, 1)).unwrap();
+/// table.set(1, &(0x5678, 2)).unwrap();
+/// table.set(0, &(0x9ABC, 3)).unwrap();
+///
+/// assert_eq!(table.len().unwrap(), 2);
+/// assert_eq!(table.get(1).unwrap(), (0x5678, 2));
+/// assert!(table.get(2).is_err());
+///
+/// let records: Vec<_> = table.records().unwrap().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(records, [(0x9ABC, 3), (0x5678, 2)]);
+/// assert_eq!(
+///     table.into_inner().into_inner(),
+///     [0x9A, 0xBC, 0x03, 0x56, 0x78, 0x02]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct RecordFile<T, S> {
+    storage: S,
+    endianness: Endianness,
+    record: PhantomData<fn() -> T>,
+}
+
+impl<T, S> RecordFile<T, S> {
+    /// Create a new record file over `storage`, encoding numbers in the given byte order.
+    pub const fn new(storage: S, endianness: Endianness) -> Self {
+        Self {
+            storage,
+            endianness,
+            record: PhantomData,
+        }
+    }
+
+    /// Return a reference to the underlying storage.
+    pub const fn get_ref(&self) -> &S {
+        &self.storage
+    }
+
+    /// Return a mutable reference to the underlying storage.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Return the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+}
+
+impl<T, S> RecordFile<T, S>
+where
+    T: FixedEncodedLen,
+    S: Seek,
+{
+    /// Return the amount of complete records in the storage.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying storage.
+    pub fn len(&mut self) -> Result<u64> {
+        let size = self.storage.seek(SeekFrom::End(0))?;
+        Ok(size.checked_div(T::ENCODED_LEN as u64).unwrap_or(0))
+    }
+
+    /// Return whether the storage contains no complete records.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying storage.
+    pub fn is_empty(&mut self) -> Result<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    fn seek_to(&mut self, index: u64) -> Result<()> {
+        let offset = index
+            .checked_mul(T::ENCODED_LEN as u64)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "record offset overflows u64"))?;
+        self.storage.seek(SeekFrom::Start(offset)).map(drop)
+    }
+}
+
+impl<T, S> RecordFile<T, S>
+where
+    T: FixedEncodedLen + ReadFrom,
+    S: Read + Seek,
+{
+    /// Read the record at `index`.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying storage, including an error of kind
+    /// [`ErrorKind::UnexpectedEof`] if there is no complete record at `index`.
+    pub fn get(&mut self, index: u64) -> Result<T> {
+        self.seek_to(index)?;
+        T::read_from(&mut self.storage, self.endianness)
+    }
+
+    /// Return an iterator over all complete records.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying storage.
+    pub fn records(&mut self) -> Result<Records<'_, T, S>> {
+        let len = self.len()?;
+        Ok(Records {
+            file: self,
+            index: 0,
+            len,
+        })
+    }
+}
+
+impl<T, S> RecordFile<T, S>
+where
+    T: FixedEncodedLen + WriteTo,
+    S: Write + Seek,
+{
+    /// Write the record at `index`, extending the storage if necessary.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying storage.
+    pub fn set(&mut self, index: u64, record: &T) -> Result<()> {
+        self.seek_to(index)?;
+        record.write_to(&mut self.storage, self.endianness)
+    }
+}
+
+/// An iterator over the records of a [`RecordFile`].
+#[derive(Debug)]
+pub struct Records<'a, T, S> {
+    file: &'a mut RecordFile<T, S>,
+    index: u64,
+    len: u64,
+}
+
+impl<T, S> Iterator for Records<'_, T, S>
+where
+    T: FixedEncodedLen + ReadFrom,
+    S: Read + Seek,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let record = self.file.get(self.index);
+        self.index += 1;
+        Some(record)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.len - self.index).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
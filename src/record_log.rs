@@ -0,0 +1,257 @@
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{fill_or_eof, fill_vec, ChecksumKind};
+
+/// Size of the length prefix of a record.
+const LEN_SIZE: usize = 4;
+/// Size of the checksum suffix of a record.
+const CRC_SIZE: usize = 4;
+
+/// An append-only log of byte records, which can be recovered after a crash.
+///
+/// Each record is stored as its length as `u32` in little endian, followed by the payload
+/// and the CRC-32 of the payload as `u32` in little endian.
+/// Every record is written with a single call to [`Write::write_all`].
+/// A record that was only partially written when the process or system crashed is detected
+/// by [`RecordLogReader`], which reports the offset the log can safely be truncated to.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{RecordLog, RecordLogReader};
+///
+/// let mut log = RecordLog::new(Vec::new());
+/// assert_eq!(log.append(b"first").unwrap(), 0);
+/// assert_eq!(log.append(b"second").unwrap(), 13);
+/// let mut bytes = log.into_inner();
+///
+/// // Simulate a torn write of a third record.
+/// bytes.extend_from_slice(&[0x05, 0x00, 0x00, 0x00, b't', b'h']);
+///
+/// let mut reader = RecordLogReader::new(&bytes[..]);
+/// let records: Vec<_> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(records, [b"first".to_vec(), b"second".to_vec()]);
+/// assert!(reader.is_torn());
+/// assert_eq!(reader.valid_len(), 27);
+/// ```
+#[derive(Debug)]
+pub struct RecordLog<W> {
+    writer: W,
+    offset: u64,
+}
+
+impl<W> RecordLog<W> {
+    /// Create a new record log writing to the start of an empty `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self::at_offset(writer, 0)
+    }
+
+    /// Create a new record log appending to `writer`, which already contains `offset` bytes of records.
+    ///
+    /// When reopening a log, `offset` is usually [`RecordLogReader::valid_len`],
+    /// after truncating the log to that length.
+    pub const fn at_offset(writer: W, offset: u64) -> Self {
+        Self { writer, offset }
+    }
+
+    /// Return the offset at which the next record will be written.
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Return a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> RecordLog<W>
+where
+    W: Write,
+{
+    /// Append a record and return its offset.
+    ///
+    /// The record is not flushed or synced to persistent storage.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the payload is larger than
+    /// [`u32::MAX`] bytes, or any I/O error of the underlying writer.
+    /// In the latter case, the record may have been written partially and the log should be
+    /// recovered with [`RecordLogReader`] before appending further records.
+    pub fn append(&mut self, payload: &[u8]) -> Result<u64> {
+        let len = u32::try_from(payload.len()).map_err(|error| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("record of {} bytes is too large: {error}", payload.len()),
+            )
+        })?;
+        let mut record = Vec::with_capacity(LEN_SIZE + payload.len() + CRC_SIZE);
+        record.extend_from_slice(&len.to_le_bytes());
+        record.extend_from_slice(payload);
+        record.extend_from_slice(&ChecksumKind::Crc32.compute(payload).to_le_bytes());
+        self.writer.write_all(&record)?;
+
+        let offset = self.offset;
+        self.offset += record.len() as u64;
+        Ok(offset)
+    }
+
+    /// Flush the underlying writer.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying writer.
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl RecordLog<File> {
+    /// Append a record, sync it to persistent storage and return its offset.
+    ///
+    /// Once this returns, the record survives a crash of the system.
+    ///
+    /// # Errors
+    /// Returns the errors of [`RecordLog::append`] and [`File::sync_data`].
+    pub fn append_synced(&mut self, payload: &[u8]) -> Result<u64> {
+        let offset = self.append(payload)?;
+        self.sync()?;
+        Ok(offset)
+    }
+
+    /// Sync all appended records to persistent storage.
+    ///
+    /// # Errors
+    /// Returns any I/O error of [`File::sync_data`].
+    pub fn sync(&mut self) -> Result<()> {
+        self.writer.sync_data()
+    }
+}
+
+/// An iterator over the valid records of a [`RecordLog`].
+///
+/// The iterator ends at the end of the log or at the first invalid record, i.e. a record which
+/// is incomplete, longer than the maximum length or whose checksum does not match.
+/// In the latter case, [`RecordLogReader::is_torn`] returns `true` and
+/// [`RecordLogReader::valid_len`] is the offset the log can safely be truncated to.
+/// I/O errors of the underlying reader are yielded as an error, after which the iterator ends.
+///
+/// See [`RecordLog`] for an example.
+#[derive(Debug)]
+pub struct RecordLogReader<R> {
+    reader: R,
+    valid_len: u64,
+    max_len: Option<usize>,
+    torn: bool,
+    done: bool,
+}
+
+impl<R> RecordLogReader<R> {
+    /// Create a new reader over the records of `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            valid_len: 0,
+            max_len: None,
+            torn: false,
+            done: false,
+        }
+    }
+
+    /// Treat records with payloads longer than `max_len` bytes as invalid.
+    ///
+    /// This bounds the memory allocated for a record with a corrupted length.
+    #[must_use]
+    pub const fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Return the offset after the last valid record read so far.
+    pub const fn valid_len(&self) -> u64 {
+        self.valid_len
+    }
+
+    /// Return `true` if an invalid trailing record has been encountered.
+    pub const fn is_torn(&self) -> bool {
+        self.torn
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> RecordLogReader<R>
+where
+    R: Read,
+{
+    /// Read the next record, or return `Ok(None)` if the log ends or the record is invalid.
+    fn read_record(&mut self) -> Result<Option<Vec<u8>>> {
+        let mut len = [0; LEN_SIZE];
+
+        if !fill_or_eof(&mut self.reader, &mut len)? {
+            return Ok(None);
+        }
+
+        let len = u32::from_le_bytes(len) as usize;
+
+        if self.max_len.is_some_and(|max_len| len > max_len) {
+            self.torn = true;
+            return Ok(None);
+        }
+
+        let mut payload = Vec::new();
+        let mut crc = [0; CRC_SIZE];
+        fill_vec(&mut self.reader, &mut payload, len)?;
+        self.reader.read_exact(&mut crc)?;
+
+        if ChecksumKind::Crc32.compute(&payload) != u32::from_le_bytes(crc) {
+            self.torn = true;
+            return Ok(None);
+        }
+
+        self.valid_len += (LEN_SIZE + len + CRC_SIZE) as u64;
+        Ok(Some(payload))
+    }
+}
+
+impl<R> Iterator for RecordLogReader<R>
+where
+    R: Read,
+{
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.read_record() {
+            Ok(Some(payload)) => Some(Ok(payload)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                self.done = true;
+                self.torn = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
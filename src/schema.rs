@@ -0,0 +1,252 @@
+//! Layouts described at runtime.
+//!
+//! A [`Schema`] is a sequence of named [`Field`]s, each with a [`FieldType`],
+//! a byte order and an optional repetition.
+//! Applying a schema to a reader decodes its fields in order into a [`Row`] of [`Value`]s.
+//! Sizes and repetition counts can either be fixed or refer to a preceding unsigned integer field.
+//!
+//! # Examples
+//! ```
+//! use rw_exact_ext::schema::{Count, Field, FieldType, Schema, Value};
+//! use rw_exact_ext::Endianness;
+//! use std::io::Cursor;
+//!
+//! let schema = Schema::new()
+//!     .field(Field::new("version", FieldType::UInt(1)))
+//!     .field(Field::new("count", FieldType::UInt(2)).with_endianness(Endianness::Little))
+//!     .field(Field::new("samples", FieldType::Int(2)).repeated(Count::Field("count".into())))
+//!     .field(Field::new("name_len", FieldType::UInt(1)))
+//!     .field(Field::new("name", FieldType::Str(Count::Field("name_len".into()))));
+//!
+//! let bytes = [0x01, 0x02, 0x00, 0xFF, 0xFE, 0x00, 0x03, 0x02, b'h', b'i'];
+//! let row = schema.read(&mut Cursor::new(&bytes)).unwrap();
+//! assert_eq!(row.get("version"), Some(&Value::UInt(1)));
+//! assert_eq!(
+//!     row.get("samples"),
+//!     Some(&Value::List(vec![Value::Int(-2), Value::Int(3)]))
+//! );
+//! assert_eq!(row.get("name"), Some(&Value::Str("hi".into())));
+//! ```
+
+use std::io::{Error, ErrorKind, Read, Result};
+
+use crate::{fill_vec, Endianness, ReadFrom, ReadNumExt};
+
+/// Types of fields in a [`Schema`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FieldType {
+    /// One byte, where any non-zero value is `true`.
+    Bool,
+    /// An unsigned integer of the given size in bytes, at most 8.
+    UInt(usize),
+    /// A signed two's complement integer of the given size in bytes, at most 8.
+    Int(usize),
+    /// An IEEE 754 single-precision float.
+    F32,
+    /// An IEEE 754 double-precision float.
+    F64,
+    /// Raw bytes of the given size.
+    Bytes(Count),
+    /// A UTF-8 string of the given size in bytes.
+    Str(Count),
+}
+
+/// A size or repetition count of a [`Field`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Count {
+    /// A count known in advance.
+    Fixed(usize),
+    /// The value of the preceding unsigned integer field of the given name.
+    Field(String),
+}
+
+/// A named field of a [`Schema`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Type of the field.
+    pub field_type: FieldType,
+    /// Byte order of numbers in the field.
+    pub endianness: Endianness,
+    /// Amount of consecutive values of the field type, or `None` for a single value.
+    pub repeat: Option<Count>,
+}
+
+impl Field {
+    /// Create a new field containing a single value in big endian.
+    #[must_use]
+    pub fn new(name: impl Into<String>, field_type: FieldType) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+            endianness: Endianness::Big,
+            repeat: None,
+        }
+    }
+
+    /// Set the byte order of numbers in the field.
+    #[must_use]
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Repeat the field type the given amount of times, yielding a [`Value::List`].
+    #[must_use]
+    pub fn repeated(mut self, count: Count) -> Self {
+        self.repeat = Some(count);
+        self
+    }
+}
+
+/// A decoded field value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Value of a [`FieldType::Bool`] field.
+    Bool(bool),
+    /// Value of a [`FieldType::UInt`] field.
+    UInt(u64),
+    /// Value of a [`FieldType::Int`] field.
+    Int(i64),
+    /// Value of a [`FieldType::F32`] field.
+    F32(f32),
+    /// Value of a [`FieldType::F64`] field.
+    F64(f64),
+    /// Value of a [`FieldType::Bytes`] field.
+    Bytes(Vec<u8>),
+    /// Value of a [`FieldType::Str`] field.
+    Str(String),
+    /// Values of a repeated field.
+    List(Vec<Value>),
+}
+
+/// The named values decoded by a [`Schema`], in the order of its fields.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Row {
+    values: Vec<(String, Value)>,
+}
+
+impl Row {
+    /// Return the value of the first field of the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Return an iterator over the field names and values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Return the field names and values.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<(String, Value)> {
+        self.values
+    }
+
+    fn count(&self, count: &Count) -> Result<usize> {
+        match count {
+            Count::Fixed(count) => Ok(*count),
+            Count::Field(name) => match self.get(name) {
+                Some(Value::UInt(count)) => usize::try_from(*count)
+                    .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+                _ => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("count field `{name}` is not a preceding unsigned integer"),
+                )),
+            },
+        }
+    }
+}
+
+/// A layout of named fields described at runtime.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Schema {
+    fields: Vec<Field>,
+}
+
+impl Schema {
+    /// Create a new schema without fields.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Append a field.
+    #[must_use]
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Return the fields in order.
+    #[must_use]
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Read the fields in order from `reader`.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if an integer size exceeds 8
+    /// or a count refers to a field that is not a preceding unsigned integer,
+    /// or of kind [`ErrorKind::InvalidData`] if a string is not valid UTF-8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    pub fn read<R>(&self, reader: &mut R) -> Result<Row>
+    where
+        R: Read + ?Sized,
+    {
+        let mut row = Row::default();
+
+        for field in &self.fields {
+            let value = match &field.repeat {
+                None => read_value(reader, field, &row)?,
+                Some(count) => Value::List(
+                    (0..row.count(count)?)
+                        .map(|_| read_value(reader, field, &row))
+                        .collect::<Result<_>>()?,
+                ),
+            };
+            row.values.push((field.name.clone(), value));
+        }
+
+        Ok(row)
+    }
+}
+
+fn read_value<R>(mut reader: &mut R, field: &Field, row: &Row) -> Result<Value>
+where
+    R: Read + ?Sized,
+{
+    let big_endian = field.endianness.is_big();
+
+    Ok(match &field.field_type {
+        FieldType::Bool => Value::Bool(bool::read_from(reader, field.endianness)?),
+        FieldType::UInt(size) if big_endian => Value::UInt(reader.read_uint_be(*size)?),
+        FieldType::UInt(size) => Value::UInt(reader.read_uint_le(*size)?),
+        FieldType::Int(size) if big_endian => Value::Int(reader.read_int_be(*size)?),
+        FieldType::Int(size) => Value::Int(reader.read_int_le(*size)?),
+        FieldType::F32 => Value::F32(f32::read_from(reader, field.endianness)?),
+        FieldType::F64 => Value::F64(f64::read_from(reader, field.endianness)?),
+        FieldType::Bytes(size) => {
+            let mut bytes = Vec::new();
+            fill_vec(reader, &mut bytes, row.count(size)?)?;
+            Value::Bytes(bytes)
+        }
+        FieldType::Str(size) => {
+            let mut bytes = Vec::new();
+            fill_vec(reader, &mut bytes, row.count(size)?)?;
+            Value::Str(
+                String::from_utf8(bytes)
+                    .map_err(|error| Error::new(ErrorKind::InvalidData, error))?,
+            )
+        }
+    })
+}
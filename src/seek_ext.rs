@@ -0,0 +1,114 @@
+use std::io::{Read, Result, Seek, SeekFrom};
+
+use crate::{Endianness, FromWireBytes, ReadFrom};
+
+/// Offset-addressed exact reads on seekable readers.
+///
+/// Each method seeks to the given offset from the start of the stream before reading,
+/// and leaves the stream positioned after the read bytes.
+/// To keep the position of the stream, wrap the reads in [`SeekReadExt::preserving_position`].
+///
+/// Unlike [`FileExactExt`](crate::FileExactExt), which uses positional I/O without a cursor,
+/// this works on any [`Read`] + [`Seek`] implementor, such as [`Cursor`](std::io::Cursor)s
+/// and [`BufReader`](std::io::BufReader)s.
+/// Since the method names coincide with those of `FileExactExt`, this trait is not part of the prelude.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, SeekReadExt};
+/// use std::io::{Cursor, Seek};
+///
+/// // An offset table followed by the entries it points to.
+/// let bytes = [0x00, 0x04, 0x00, 0x06, 0x13, 0x37, 0x01, 0x42];
+/// let mut cursor = Cursor::new(&bytes);
+///
+/// let second: u16 = cursor.read_num_be_at(2).unwrap();
+/// let entry: (bool, u8) = cursor.read_value_at(u64::from(second), Endianness::Big).unwrap();
+/// assert_eq!(entry, (true, 0x42));
+///
+/// let first = cursor
+///     .preserving_position(|cursor| cursor.read_array_exact_at::<2>(4))
+///     .unwrap();
+/// assert_eq!(first, [0x13, 0x37]);
+/// assert_eq!(cursor.stream_position().unwrap(), 8);
+/// ```
+pub trait SeekReadExt: Read + Seek {
+    /// Read a byte array of a constant size at the given offset.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_exact_at<const SIZE: usize>(&mut self, offset: u64) -> Result<[u8; SIZE]> {
+        let mut buffer = [0; SIZE];
+        self.seek(SeekFrom::Start(offset))?;
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read a number from a byte array in big endian at the given offset.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_be_at<N, const SIZE: usize>(&mut self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Big))
+    }
+
+    /// Read a number from a byte array in little endian at the given offset.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_le_at<N, const SIZE: usize>(&mut self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Little))
+    }
+
+    /// Read a number from a byte array in native endianness at the given offset.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_num_ne_at<N, const SIZE: usize>(&mut self, offset: u64) -> Result<N>
+    where
+        N: FromWireBytes<Bytes = [u8; SIZE]>,
+    {
+        self.read_array_exact_at(offset)
+            .map(|bytes| N::from_wire_bytes(bytes, Endianness::Native))
+    }
+
+    /// Read a value of a type implementing [`ReadFrom`] at the given offset,
+    /// decoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_value_at<T>(&mut self, offset: u64, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        self.seek(SeekFrom::Start(offset))?;
+        T::read_from(self, endianness)
+    }
+
+    /// Run `read` and restore the position of the stream afterwards.
+    ///
+    /// The position is restored even if `read` fails.
+    ///
+    /// # Errors
+    /// Returns any error of `read`, or any I/O error of determining or restoring the position.
+    fn preserving_position<F, T>(&mut self, read: F) -> Result<T>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let position = self.stream_position()?;
+        let result = read(self);
+        self.seek(SeekFrom::Start(position))?;
+        result
+    }
+}
+
+impl<T> SeekReadExt for T where T: Read + Seek {}
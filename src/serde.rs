@@ -0,0 +1,875 @@
+//! A compact binary `serde` data format built on [`ReadBytesExt`] and [`WriteNumExt`].
+//!
+//! The format is not self-describing:
+//!
+//! * Booleans are encoded as one byte, `0` or `1`.
+//! * Numbers are encoded with their natural size in the configured byte order.
+//! * `char`s are encoded as `u32`.
+//! * Strings, byte arrays, sequences and maps are prefixed with their length as `u64`.
+//! * Options are prefixed with a byte, `0` for `None` and `1` for `Some`.
+//! * Enum variants are prefixed with their index as `u32`.
+//! * Structs and tuples are encoded as the concatenation of their fields.
+//!
+//! # Examples
+//! ```
+//! use rw_exact_ext::serde::{from_reader, to_writer};
+//! use rw_exact_ext::Endianness;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! enum Kind {
+//!     Ping,
+//!     Data(Vec<u8>),
+//! }
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Message {
+//!     id: u16,
+//!     name: String,
+//!     kind: Kind,
+//! }
+//!
+//! let message = Message {
+//!     id: 0x1337,
+//!     name: "hi".into(),
+//!     kind: Kind::Data(vec![0xAB]),
+//! };
+//!
+//! let mut bytes = Vec::new();
+//! to_writer(&mut bytes, &message, Endianness::Big).unwrap();
+//! assert_eq!(
+//!     bytes,
+//!     [
+//!         0x13, 0x37, // id
+//!         0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i', // name
+//!         0, 0, 0, 1, // variant index
+//!         0, 0, 0, 0, 0, 0, 0, 1, 0xAB, // data
+//!     ]
+//! );
+//!
+//! let decoded: Message = from_reader(bytes.as_slice(), Endianness::Big).unwrap();
+//! assert_eq!(decoded, message);
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, ErrorKind, Read, Write};
+
+use ::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use ::serde::ser::{self, Serialize};
+
+use crate::{Endianness, FromWireBytes, ReadBytesExt, ToWireBytes, WriteNumExt};
+
+/// Serialize `value` to `writer`, encoding numbers in the given byte order.
+///
+/// # Errors
+/// Returns any I/O error of `writer` or an error of kind [`ErrorKind::InvalidInput`]
+/// if `value` cannot be serialized.
+pub fn to_writer<W, T>(writer: W, value: &T, endianness: Endianness) -> io::Result<()>
+where
+    W: Write,
+    T: Serialize + ?Sized,
+{
+    value
+        .serialize(&mut Serializer::new(writer, endianness))
+        .map_err(io::Error::from)
+}
+
+/// Deserialize a value from `reader`, decoding numbers in the given byte order.
+///
+/// # Errors
+/// Returns any I/O error of `reader` or an error of kind [`ErrorKind::InvalidData`]
+/// if the data does not describe a valid value.
+pub fn from_reader<R, T>(reader: R, endianness: Endianness) -> io::Result<T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    T::deserialize(&mut Deserializer::new(reader, endianness)).map_err(io::Error::from)
+}
+
+/// Error of the serializer and deserializer.
+///
+/// This is a thin wrapper around [`io::Error`].
+#[derive(Debug)]
+pub struct Error(io::Error);
+
+impl Error {
+    fn invalid_input(message: impl Display) -> Self {
+        Self(io::Error::new(ErrorKind::InvalidInput, message.to_string()))
+    }
+
+    fn invalid_data(message: impl Display) -> Self {
+        Self(io::Error::new(ErrorKind::InvalidData, message.to_string()))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self::invalid_input(msg)
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self::invalid_data(msg)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self(error)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        error.0
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// A serializer writing the binary format to a [`Write`] implementor.
+#[derive(Debug)]
+pub struct Serializer<W> {
+    writer: W,
+    endianness: Endianness,
+}
+
+impl<W> Serializer<W> {
+    /// Create a new serializer encoding numbers in the given byte order.
+    pub const fn new(writer: W, endianness: Endianness) -> Self {
+        Self { writer, endianness }
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+macro_rules! serialize_num {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $type) -> Result<()> {
+                self.writer
+                    .write_all(&v.to_wire_bytes(self.endianness))
+                    .map_err(Error::from)
+            }
+        )*
+    };
+}
+
+impl<W> Serializer<W>
+where
+    W: Write,
+{
+    fn serialize_len(&mut self, len: usize) -> Result<()> {
+        let len = u64::try_from(len).map_err(Error::invalid_input)?;
+        ser::Serializer::serialize_u64(self, len)
+    }
+
+    fn serialize_variant_index(&mut self, index: u32) -> Result<()> {
+        ser::Serializer::serialize_u32(self, index)
+    }
+}
+
+impl<W> ser::Serializer for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    serialize_num!(
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+    );
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.writer.write_bool(v).map_err(Error::from)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_u32(v.into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.serialize_len(v.len())?;
+        self.writer.write_all(v).map_err(Error::from)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_bool(false)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.serialize_bool(true)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_variant_index(variant_index)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.serialize_variant_index(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        let len = len.ok_or_else(|| Error::invalid_input("sequence length must be known"))?;
+        self.serialize_len(len)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.serialize_variant_index(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self> {
+        let len = len.ok_or_else(|| Error::invalid_input("map length must be known"))?;
+        self.serialize_len(len)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.serialize_variant_index(variant_index)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<W> ser::SerializeSeq for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeTuple for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeTupleStruct for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeTupleVariant for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeMap for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeStruct for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<W> ser::SerializeStructVariant for &mut Serializer<W>
+where
+    W: Write,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A deserializer reading the binary format from a [`Read`] implementor.
+#[derive(Debug)]
+pub struct Deserializer<R> {
+    reader: R,
+    endianness: Endianness,
+    max_len: Option<usize>,
+}
+
+impl<R> Deserializer<R> {
+    /// Create a new deserializer decoding numbers in the given byte order.
+    pub const fn new(reader: R, endianness: Endianness) -> Self {
+        Self {
+            reader,
+            endianness,
+            max_len: None,
+        }
+    }
+
+    /// Limit the length of strings, byte arrays, sequences and maps.
+    ///
+    /// Longer lengths result in an error of kind [`ErrorKind::InvalidData`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::serde::Deserializer;
+    /// use rw_exact_ext::Endianness;
+    /// use serde::Deserialize;
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 3, b'a', b'b', b'c'];
+    /// let mut deserializer = Deserializer::new(&bytes[..], Endianness::Big).with_max_len(2);
+    /// assert!(String::deserialize(&mut deserializer).is_err());
+    /// ```
+    #[must_use]
+    pub const fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+macro_rules! read_num {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(&mut self) -> Result<$type> {
+                let bytes = self.reader.read_array_exact()?;
+                Ok(<$type>::from_wire_bytes(bytes, self.endianness))
+            }
+        )*
+    };
+}
+
+macro_rules! deserialize_num {
+    ($($method:ident => $visit:ident($read:ident)),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>,
+            {
+                visitor.$visit(self.$read()?)
+            }
+        )*
+    };
+}
+
+impl<R> Deserializer<R>
+where
+    R: Read,
+{
+    read_num!(
+        read_i8(i8),
+        read_i16(i16),
+        read_i32(i32),
+        read_i64(i64),
+        read_i128(i128),
+        read_u8(u8),
+        read_u16(u16),
+        read_u32(u32),
+        read_u64(u64),
+        read_u128(u128),
+        read_f32(f32),
+        read_f64(f64),
+    );
+
+    fn read_len(&mut self) -> Result<usize> {
+        let len = usize::try_from(self.read_u64()?).map_err(Error::invalid_data)?;
+
+        if self.max_len.is_some_and(|max_len| len > max_len) {
+            return Err(Error::invalid_data(format_args!(
+                "length {len} exceeds maximum"
+            )));
+        }
+
+        Ok(len)
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(Error::invalid_data(format_args!("invalid boolean: {byte}"))),
+        }
+    }
+
+    fn read_byte_buf(&mut self) -> Result<Vec<u8>> {
+        let len = self.read_len()?;
+        self.reader
+            .try_read_vec_exact(len, None)
+            .map_err(Error::from)
+    }
+}
+
+impl<'de, R> de::Deserializer<'de> for &mut Deserializer<R>
+where
+    R: Read,
+{
+    type Error = Error;
+
+    deserialize_num!(
+        deserialize_i8 => visit_i8(read_i8),
+        deserialize_i16 => visit_i16(read_i16),
+        deserialize_i32 => visit_i32(read_i32),
+        deserialize_i64 => visit_i64(read_i64),
+        deserialize_i128 => visit_i128(read_i128),
+        deserialize_u8 => visit_u8(read_u8),
+        deserialize_u16 => visit_u16(read_u16),
+        deserialize_u32 => visit_u32(read_u32),
+        deserialize_u64 => visit_u64(read_u64),
+        deserialize_u128 => visit_u128(read_u128),
+        deserialize_f32 => visit_f32(read_f32),
+        deserialize_f64 => visit_f64(read_f64),
+        deserialize_bool => visit_bool(read_bool),
+        deserialize_bytes => visit_byte_buf(read_byte_buf),
+        deserialize_byte_buf => visit_byte_buf(read_byte_buf),
+        deserialize_identifier => visit_u32(read_u32),
+    );
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::invalid_input("format is not self-describing"))
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let code = self.read_u32()?;
+        let char = char::from_u32(code)
+            .ok_or_else(|| Error::invalid_data(format_args!("invalid char: {code:#x}")))?;
+        visitor.visit_char(char)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let bytes = self.read_byte_buf()?;
+        visitor.visit_string(String::from_utf8(bytes).map_err(Error::invalid_data)?)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        if self.read_bool()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let remaining = self.read_len()?;
+        visitor.visit_seq(Access {
+            deserializer: self,
+            remaining,
+        })
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(Access {
+            deserializer: self,
+            remaining: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let remaining = self.read_len()?;
+        visitor.visit_map(Access {
+            deserializer: self,
+            remaining,
+        })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::invalid_input("format is not self-describing"))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<'de, R> EnumAccess<'de> for &mut Deserializer<R>
+where
+    R: Read,
+{
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let index = self.read_u32()?;
+        let value = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(index))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, R> VariantAccess<'de> for &mut Deserializer<R>
+where
+    R: Read,
+{
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
+
+/// Access to a fixed amount of sequence elements or map entries.
+struct Access<'a, R> {
+    deserializer: &'a mut Deserializer<R>,
+    remaining: usize,
+}
+
+impl<'de, R> SeqAccess<'de> for Access<'_, R>
+where
+    R: Read,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, R> MapAccess<'de> for Access<'_, R>
+where
+    R: Read,
+{
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
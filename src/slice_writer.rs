@@ -0,0 +1,131 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{Error, ErrorKind, Result, Write};
+
+/// A writer into a fixed-capacity byte slice, which reports overflows precisely.
+///
+/// Unlike writing to `&mut [u8]`, every write either fits completely or fails without
+/// writing anything, with an error of kind [`ErrorKind::InvalidInput`] wrapping a [`CapacityError`],
+/// which states how many bytes were needed and how many were available.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{CapacityError, SliceWriter, WriteNumExt};
+/// use std::io::Write;
+///
+/// let mut buffer = [0; 4];
+/// let mut writer = SliceWriter::new(&mut buffer);
+/// writer.write_all(&[0x13, 0x37, 0x42]).unwrap();
+/// assert_eq!(writer.written(), 3);
+///
+/// let error = writer.write_all(&[0xAB, 0xCD]).unwrap_err();
+/// let capacity = error.get_ref().unwrap().downcast_ref::<CapacityError>().unwrap();
+/// assert_eq!((capacity.needed(), capacity.available()), (2, 1));
+/// assert_eq!(error.to_string(), "buffer too small: needed 2 bytes, have 1");
+///
+/// writer.write_bool(true).unwrap();
+/// assert_eq!(writer.into_written(), [0x13, 0x37, 0x42, 0x01]);
+/// ```
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    written: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Create a new writer into `buffer`, starting at its beginning.
+    pub const fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, written: 0 }
+    }
+
+    /// Return the amount of bytes written so far.
+    #[must_use]
+    pub const fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Return the amount of bytes which can still be written.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.buffer.len() - self.written
+    }
+
+    /// Return the total capacity of the underlying buffer.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Return the bytes written so far.
+    #[must_use]
+    pub fn as_written(&self) -> &[u8] {
+        &self.buffer[..self.written]
+    }
+
+    /// Return the written part of the underlying buffer.
+    #[must_use]
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buffer[..self.written]
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        let remaining = self.remaining();
+
+        if buf.len() > remaining {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                CapacityError {
+                    needed: buf.len(),
+                    available: remaining,
+                },
+            ));
+        }
+
+        self.buffer[self.written..self.written + buf.len()].copy_from_slice(buf);
+        self.written += buf.len();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Error of a write exceeding the remaining capacity of a [`SliceWriter`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CapacityError {
+    needed: usize,
+    available: usize,
+}
+
+impl CapacityError {
+    /// Return the amount of bytes the failed write needed.
+    #[must_use]
+    pub const fn needed(&self) -> usize {
+        self.needed
+    }
+
+    /// Return the amount of bytes that were available.
+    #[must_use]
+    pub const fn available(&self) -> usize {
+        self.available
+    }
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer too small: needed {} bytes, have {}",
+            self.needed, self.available
+        )
+    }
+}
+
+impl std::error::Error for CapacityError {}
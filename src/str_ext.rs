@@ -0,0 +1,479 @@
+use std::ffi::{OsStr, OsString};
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    check_size, fill_vec, os_string, Endianness, FromWireBytes, ReadFrom, ReadNumExt, ToWireBytes,
+    WriteNumExt, WriteTo,
+};
+
+/// Exact reads of strings, OS strings and paths.
+pub trait ReadStrExt: ReadNumExt {
+    /// Read a UTF-16 string of `code_units` code units in the given byte order.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the string is not valid UTF-16.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadStrExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x48, 0x00, 0x69, 0x00, 0x3D, 0xD8, 0x00, 0xDE];
+    /// let string = Cursor::new(&bytes)
+    ///     .read_utf16_string_exact(4, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(string, "Hi😀");
+    ///
+    /// assert!(Cursor::new(&bytes[..6])
+    ///     .read_utf16_string_exact(3, Endianness::Little)
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_utf16_string_exact(
+        &mut self,
+        code_units: usize,
+        endianness: Endianness,
+    ) -> Result<String> {
+        let units = read_utf16_units(self, code_units, endianness)?;
+        String::from_utf16(&units).map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a UTF-16 string of `code_units` code units in the given byte order.
+    ///
+    /// Invalid code units are replaced with [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadStrExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x48, 0xD8, 0x3D];
+    /// let string = Cursor::new(&bytes)
+    ///     .read_utf16_string_exact_lossy(2, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(string, "H\u{FFFD}");
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_utf16_string_exact_lossy(
+        &mut self,
+        code_units: usize,
+        endianness: Endianness,
+    ) -> Result<String> {
+        read_utf16_units(self, code_units, endianness).map(|units| String::from_utf16_lossy(&units))
+    }
+
+    /// Read a UTF-16 string of `code_units` code units, detecting the byte order from a BOM.
+    ///
+    /// If the first code unit is a byte order mark, it determines the byte order and is
+    /// not part of the returned string. Otherwise, `default` is used as byte order.
+    /// `code_units` includes the byte order mark, if any.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the string is not valid UTF-16.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadStrExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0xFE, 0xFF, 0x00, 0x48, 0x00, 0x69];
+    /// let string = Cursor::new(&bytes)
+    ///     .read_utf16_string_with_bom(3, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(string, "Hi");
+    ///
+    /// let bytes = [0x48, 0x00, 0x69, 0x00];
+    /// let string = Cursor::new(&bytes)
+    ///     .read_utf16_string_with_bom(2, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(string, "Hi");
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_utf16_string_with_bom(
+        &mut self,
+        code_units: usize,
+        default: Endianness,
+    ) -> Result<String> {
+        let units = read_utf16_units_with_bom(self, code_units, default)?;
+        String::from_utf16(&units).map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a UTF-16 string of `code_units` code units, detecting the byte order from a BOM.
+    ///
+    /// Invalid code units are replaced with [`char::REPLACEMENT_CHARACTER`].
+    /// For the handling of the byte order mark please refer to
+    /// [`read_utf16_string_with_bom`](Self::read_utf16_string_with_bom).
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_utf16_string_with_bom_lossy(
+        &mut self,
+        code_units: usize,
+        default: Endianness,
+    ) -> Result<String> {
+        read_utf16_units_with_bom(self, code_units, default)
+            .map(|units| String::from_utf16_lossy(&units))
+    }
+
+    /// Read an OS string in a portable encoding.
+    ///
+    /// The encoding consists of a tag byte, the payload size as `u32` in big endian,
+    /// and the payload. Tag `0` denotes UTF-8, which is decoded on every platform.
+    /// Tag `1` denotes arbitrary bytes, which are only decoded on Unix.
+    /// Tag `2` denotes potentially ill-formed UTF-16 in little endian, which is only decoded on Windows.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the payload size exceeds `max_size`
+    /// or the payload cannot be decoded on the current platform.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadStrExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x00, 0x00, 0x00, 0x03, b'f', b'o', b'o'];
+    /// let string = Cursor::new(&bytes).read_os_string(Some(255)).unwrap();
+    /// assert_eq!(string, "foo");
+    /// assert!(Cursor::new(&bytes).read_os_string(Some(2)).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_os_string(&mut self, max_size: Option<usize>) -> Result<OsString> {
+        let [tag] = self.read_array_exact()?;
+        let size = self.read_array_exact().map(u32::from_be_bytes)? as usize;
+        check_size(size, max_size)?;
+        let payload = self.read_vec_exact(size)?;
+        os_string::decode(tag, payload)
+    }
+
+    /// Read a path in a portable encoding.
+    ///
+    /// For the encoding please refer to [`read_os_string`](Self::read_os_string).
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_path_buf(&mut self, max_size: Option<usize>) -> Result<PathBuf> {
+        self.read_os_string(max_size).map(PathBuf::from)
+    }
+
+    /// Read a UTF-8 string prefixed with its length in bytes as `P` in the given byte order.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the length does not fit into `usize`,
+    /// exceeds `max_len` or the string is not valid UTF-8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadStrExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x02, b'h', b'i', 0x04, b's', b'h', b'o'];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_string_prefixed::<u16>(Endianness::Big, None).unwrap(), "hi");
+    /// assert!(cursor.read_string_prefixed::<u8>(Endianness::Big, None).is_err());
+    ///
+    /// assert!(Cursor::new([0x01, 0xFF])
+    ///     .read_string_prefixed::<u8>(Endianness::Big, None)
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_string_prefixed<P>(
+        &mut self,
+        endianness: Endianness,
+        max_len: Option<usize>,
+    ) -> Result<String>
+    where
+        P: ReadFrom + TryInto<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        let len = P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        check_size(len, max_len)?;
+        String::from_utf8(self.read_vec_exact(len)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a Pascal string, i.e. a UTF-8 string prefixed with its length in bytes as `u8`.
+    ///
+    /// If `max_field_len` is given, the string is stored in a fixed field of `max_field_len` bytes
+    /// after the length byte, whose padding is skipped.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the length exceeds `max_field_len`
+    /// or the string is not valid UTF-8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadStrExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x02, b'h', b'i', 0x02, b'h', b'o', 0x00, 0x00, 0xFF];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_pascal_string(None).unwrap(), "hi");
+    /// assert_eq!(cursor.read_pascal_string(Some(4)).unwrap(), "ho");
+    /// assert_eq!(cursor.position(), 8);
+    ///
+    /// assert!(Cursor::new(&bytes).read_pascal_string(Some(1)).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_pascal_string(&mut self, max_field_len: Option<u8>) -> Result<String> {
+        let [len] = self.read_array_exact()?;
+        let mut bytes = match max_field_len {
+            Some(max_field_len) if len > max_field_len => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Pascal string length {len} exceeds field length {max_field_len}"),
+                ))
+            }
+            Some(max_field_len) => self.read_vec_exact(max_field_len.into())?,
+            None => self.read_vec_exact(len.into())?,
+        };
+        bytes.truncate(len.into());
+        String::from_utf8(bytes).map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Read a UTF-8 string prefixed with its length in bytes as unsigned LEB128 variable-length integer.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the length does not fit into `usize`,
+    /// exceeds `max_len` or the string is not valid UTF-8.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadStrExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x02, b'h', b'i', 0x01, 0xFF];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// assert_eq!(cursor.read_string_varint_prefixed(Some(2)).unwrap(), "hi");
+    /// assert!(cursor.read_string_varint_prefixed(None).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_string_varint_prefixed(&mut self, max_len: Option<usize>) -> Result<String> {
+        String::from_utf8(self.read_vec_varint_prefixed(max_len)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+}
+
+impl<T> ReadStrExt for T where T: Read {}
+
+/// Writes of strings, OS strings and paths.
+pub trait WriteStrExt: WriteNumExt {
+    /// Write a string as UTF-16 in the given byte order, without a byte order mark.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteStrExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_utf16_str("Hi😀", Endianness::Little).unwrap();
+    /// assert_eq!(bytes, [0x48, 0x00, 0x69, 0x00, 0x3D, 0xD8, 0x00, 0xDE]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_utf16_str(&mut self, string: &str, endianness: Endianness) -> Result<()> {
+        let bytes: Vec<u8> = string
+            .encode_utf16()
+            .flat_map(|unit| unit.to_wire_bytes(endianness))
+            .collect();
+        self.write_all(&bytes)
+    }
+
+    /// Write an OS string in a portable encoding.
+    ///
+    /// The string is encoded as UTF-8 whenever possible, and in the platform's
+    /// native representation otherwise.
+    /// For the encoding please refer to [`ReadStrExt::read_os_string`].
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the payload exceeds `u32::MAX` bytes.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{ReadStrExt, WriteStrExt};
+    /// use std::ffi::OsStr;
+    /// use std::io::Cursor;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_os_str(OsStr::new("foo")).unwrap();
+    /// assert_eq!(bytes, [0x00, 0x00, 0x00, 0x00, 0x03, b'f', b'o', b'o']);
+    ///
+    /// #[cfg(unix)]
+    /// {
+    ///     use std::os::unix::ffi::OsStrExt;
+    ///
+    ///     let name = OsStr::from_bytes(b"caf\xE9");
+    ///     let mut bytes = Vec::new();
+    ///     bytes.write_os_str(name).unwrap();
+    ///     assert_eq!(bytes[0], 1);
+    ///     assert_eq!(Cursor::new(&bytes).read_os_string(None).unwrap(), name);
+    /// }
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_os_str(&mut self, string: &OsStr) -> Result<()> {
+        let (tag, payload) = os_string::encode(string)?;
+        let size = u32::try_from(payload.len())
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+        self.write_all(&[tag])?;
+        self.write_all(&size.to_be_bytes())?;
+        self.write_all(&payload)
+    }
+
+    /// Write a path in a portable encoding.
+    ///
+    /// For the encoding please refer to [`WriteStrExt::write_os_str`].
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_path(&mut self, path: &Path) -> Result<()> {
+        self.write_os_str(path.as_os_str())
+    }
+
+    /// Write a UTF-8 string prefixed with its length in bytes as `P` in the given byte order.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the length does not fit into `P`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteStrExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_str_prefixed::<u16>("hi", Endianness::Little).unwrap();
+    /// assert_eq!(bytes, [0x02, 0x00, b'h', b'i']);
+    ///
+    /// let long = "x".repeat(256);
+    /// assert!(Vec::new().write_str_prefixed::<u8>(&long, Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_str_prefixed<P>(&mut self, string: &str, endianness: Endianness) -> Result<()>
+    where
+        P: WriteTo + TryFrom<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+    {
+        P::try_from(string.len())
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+            .write_to(self, endianness)?;
+        self.write_all(string.as_bytes())
+    }
+
+    /// Write a Pascal string, i.e. a UTF-8 string prefixed with its length in bytes as `u8`.
+    ///
+    /// If `max_field_len` is given, the string is written into a fixed field of `max_field_len` bytes
+    /// after the length byte, which is filled up with `padding`.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the string is longer than 255 bytes
+    /// or `max_field_len`.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteStrExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_pascal_string("hi", None, 0x00).unwrap();
+    /// bytes.write_pascal_string("ho", Some(4), b' ').unwrap();
+    /// assert_eq!(bytes, [0x02, b'h', b'i', 0x02, b'h', b'o', b' ', b' ']);
+    ///
+    /// assert!(Vec::new().write_pascal_string("hi", Some(1), 0x00).is_err());
+    /// assert!(Vec::new().write_pascal_string(&"x".repeat(256), None, 0x00).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_pascal_string(
+        &mut self,
+        string: &str,
+        max_field_len: Option<u8>,
+        padding: u8,
+    ) -> Result<()> {
+        let len = u8::try_from(string.len())
+            .ok()
+            .filter(|&len| max_field_len.is_none_or(|max_field_len| len <= max_field_len))
+            .ok_or_else(|| {
+                Error::new(ErrorKind::InvalidInput, "string too long for Pascal string")
+            })?;
+        self.write_all(&[len])?;
+        self.write_all(string.as_bytes())?;
+
+        if let Some(max_field_len) = max_field_len {
+            self.write_all(&vec![padding; usize::from(max_field_len - len)])?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a UTF-8 string prefixed with its length in bytes as unsigned LEB128 variable-length integer.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteStrExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_str_varint_prefixed("hi").unwrap();
+    /// assert_eq!(bytes, [0x02, b'h', b'i']);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_str_varint_prefixed(&mut self, string: &str) -> Result<()> {
+        self.write_bytes_varint_prefixed(string.as_bytes())
+    }
+}
+
+impl<T> WriteStrExt for T where T: Write {}
+
+/// Read `code_units` UTF-16 code units in the given byte order.
+fn read_utf16_units<R>(
+    reader: &mut R,
+    code_units: usize,
+    endianness: Endianness,
+) -> Result<Vec<u16>>
+where
+    R: Read + ?Sized,
+{
+    let size = code_units
+        .checked_mul(2)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "too many code units"))?;
+    let mut bytes = Vec::with_capacity(size);
+    fill_vec(reader, &mut bytes, size)?;
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|chunk| u16::from_wire_bytes([chunk[0], chunk[1]], endianness))
+        .collect())
+}
+
+/// Read `code_units` UTF-16 code units, stripping a leading byte order mark.
+fn read_utf16_units_with_bom<R>(
+    reader: &mut R,
+    code_units: usize,
+    default: Endianness,
+) -> Result<Vec<u16>>
+where
+    R: Read + ?Sized,
+{
+    if code_units == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut bom = [0; 2];
+    reader.read_exact(&mut bom)?;
+    let endianness = match bom {
+        [0xFE, 0xFF] => Endianness::Big,
+        [0xFF, 0xFE] => Endianness::Little,
+        _ => {
+            let first = u16::from_wire_bytes(bom, default);
+            let mut units = vec![first];
+            units.extend(read_utf16_units(reader, code_units - 1, default)?);
+            return Ok(units);
+        }
+    };
+
+    read_utf16_units(reader, code_units - 1, endianness)
+}
@@ -0,0 +1,183 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+/// A reader that copies every byte it reads to a secondary writer.
+///
+/// If writing to the secondary writer fails, the bytes read are still returned and the bytes
+/// not written are kept pending. The next read retries writing them before reading further,
+/// and returns the error if it persists, so that no bytes are lost on either side.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{ReadBytesExt, TeeReader};
+///
+/// let bytes = [0xAB, 0xCD, 0xEF, 0x42];
+/// let mut reader = TeeReader::new(&bytes[..], Vec::new());
+/// let _: [u8; 3] = reader.read_array_exact().unwrap();
+/// let (_, archive) = reader.into_inner();
+/// assert_eq!(archive, [0xAB, 0xCD, 0xEF]);
+/// ```
+///
+/// Bytes which could not be written are retried:
+/// ```
+/// use rw_exact_ext::TeeReader;
+/// use std::io::{Cursor, Read};
+///
+/// let mut archive = [0; 2];
+/// let mut reader = TeeReader::new(&[0xAB, 0xCD, 0xEF][..], Cursor::new(&mut archive[..]));
+/// let mut buffer = [0; 3];
+/// assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+/// assert_eq!(reader.pending(), [0xEF]);
+/// assert!(reader.read(&mut buffer).is_err());
+/// assert_eq!(archive, [0xAB, 0xCD]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TeeReader<R, W> {
+    reader: R,
+    writer: W,
+    pending: Vec<u8>,
+}
+
+impl<R, W> TeeReader<R, W> {
+    /// Create a new tee reader that copies the data read from `reader` to `writer`.
+    pub const fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Return references to the underlying reader and writer.
+    pub const fn get_ref(&self) -> (&R, &W) {
+        (&self.reader, &self.writer)
+    }
+
+    /// Return mutable references to the underlying reader and writer.
+    pub fn get_mut(&mut self) -> (&mut R, &mut W) {
+        (&mut self.reader, &mut self.writer)
+    }
+
+    /// Return the bytes which have been read, but not yet written to the writer.
+    #[must_use]
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Return the underlying reader and writer.
+    ///
+    /// Pending bytes are discarded.
+    pub fn into_inner(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R, W> Read for TeeReader<R, W>
+where
+    R: Read,
+    W: Write,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if !self.pending.is_empty() {
+            let (written, result) = write_prefix(&mut self.writer, &self.pending);
+            self.pending.drain(..written);
+            result?;
+        }
+
+        let n = self.reader.read(buf)?;
+        let (written, result) = write_prefix(&mut self.writer, &buf[..n]);
+
+        // The error is returned by the next read, which retries writing the pending bytes.
+        if result.is_err() {
+            self.pending.extend_from_slice(&buf[written..n]);
+        }
+
+        Ok(n)
+    }
+}
+
+/// Write as much of `bytes` as possible like [`Write::write_all`]
+/// and return the amount of bytes written along with the result.
+fn write_prefix<W>(writer: &mut W, bytes: &[u8]) -> (usize, Result<()>)
+where
+    W: Write,
+{
+    let mut written = 0;
+
+    while written < bytes.len() {
+        match writer.write(&bytes[written..]) {
+            Ok(0) => {
+                return (
+                    written,
+                    Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    )),
+                );
+            }
+            Ok(n) => written += n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return (written, Err(error)),
+        }
+    }
+
+    (written, Ok(()))
+}
+
+/// A writer that duplicates every write to a secondary writer.
+///
+/// Only the bytes accepted by the primary writer are passed on to the secondary writer.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{TeeWriter, WriteNumExt};
+///
+/// let mut writer = TeeWriter::new(Vec::new(), Vec::new());
+/// writer.write_bool(true).unwrap();
+/// let (primary, secondary) = writer.into_inner();
+/// assert_eq!(primary, [0x01]);
+/// assert_eq!(secondary, [0x01]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TeeWriter<W1, W2> {
+    primary: W1,
+    secondary: W2,
+}
+
+impl<W1, W2> TeeWriter<W1, W2> {
+    /// Create a new tee writer that writes to both `primary` and `secondary`.
+    pub const fn new(primary: W1, secondary: W2) -> Self {
+        Self { primary, secondary }
+    }
+
+    /// Return references to the underlying writers.
+    pub const fn get_ref(&self) -> (&W1, &W2) {
+        (&self.primary, &self.secondary)
+    }
+
+    /// Return mutable references to the underlying writers.
+    pub fn get_mut(&mut self) -> (&mut W1, &mut W2) {
+        (&mut self.primary, &mut self.secondary)
+    }
+
+    /// Return the underlying writers.
+    pub fn into_inner(self) -> (W1, W2) {
+        (self.primary, self.secondary)
+    }
+}
+
+impl<W1, W2> Write for TeeWriter<W1, W2>
+where
+    W1: Write,
+    W2: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let n = self.primary.write(buf)?;
+        self.secondary.write_all(&buf[..n])?;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.primary.flush()?;
+        self.secondary.flush()
+    }
+}
@@ -0,0 +1,319 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::time::{Duration, SystemTime};
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::{Endianness, TimestampFormat};
+use crate::{ReadNumExt, WriteNumExt};
+
+/// Exact reads of points in time and durations.
+pub trait ReadTimeExt: ReadNumExt {
+    /// Read a `SystemTime` from a `u64` of seconds since the Unix epoch in big endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the time cannot be represented.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadTimeExt;
+    /// use std::io::Cursor;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let bytes = 1_700_000_000u64.to_be_bytes();
+    /// let time = Cursor::new(&bytes).read_system_time_secs_be().unwrap();
+    /// assert_eq!(time, SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_system_time_secs_be(&mut self) -> Result<SystemTime> {
+        self.read_array_exact()
+            .map(u64::from_be_bytes)
+            .and_then(system_time_from_secs)
+    }
+
+    /// Read a `SystemTime` from a `u64` of seconds since the Unix epoch in little endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the time cannot be represented.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_system_time_secs_le(&mut self) -> Result<SystemTime> {
+        self.read_array_exact()
+            .map(u64::from_le_bytes)
+            .and_then(system_time_from_secs)
+    }
+
+    /// Read a `Duration` from a `u64` of seconds followed by a `u32` of nanoseconds in big endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the nanoseconds exceed one second.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadTimeExt;
+    /// use std::io::Cursor;
+    /// use std::time::Duration;
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 5, 0x1D, 0xCD, 0x65, 0x00];
+    /// let duration = Cursor::new(&bytes).read_duration_be().unwrap();
+    /// assert_eq!(duration, Duration::new(5, 500_000_000));
+    ///
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 5, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// assert!(Cursor::new(&bytes).read_duration_be().is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_duration_be(&mut self) -> Result<Duration> {
+        let secs = self.read_array_exact().map(u64::from_be_bytes)?;
+        let nanos = self.read_array_exact().map(u32::from_be_bytes)?;
+        duration_from_parts(secs, nanos)
+    }
+
+    /// Read a `Duration` from a `u64` of seconds followed by a `u32` of nanoseconds in little endian.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the nanoseconds exceed one second.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_duration_le(&mut self) -> Result<Duration> {
+        let secs = self.read_array_exact().map(u64::from_le_bytes)?;
+        let nanos = self.read_array_exact().map(u32::from_le_bytes)?;
+        duration_from_parts(secs, nanos)
+    }
+
+    /// Read a `chrono::DateTime<Utc>` in the given wire format.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the timestamp is out of range.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::DateTime;
+    /// use rw_exact_ext::{Endianness, ReadTimeExt, TimestampFormat};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = (-1_500i64).to_le_bytes();
+    /// let time = Cursor::new(&bytes)
+    ///     .read_chrono_date_time(TimestampFormat::UnixMillisI64, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(time, DateTime::from_timestamp(-2, 500_000_000).unwrap());
+    ///
+    /// let bytes = 116_444_736_000_000_000u64.to_le_bytes();
+    /// let time = Cursor::new(&bytes)
+    ///     .read_chrono_date_time(TimestampFormat::FileTime, Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(time, DateTime::UNIX_EPOCH);
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_chrono_date_time(
+        &mut self,
+        format: TimestampFormat,
+        endianness: Endianness,
+    ) -> Result<chrono::DateTime<chrono::Utc>> {
+        let (secs, nanos) = format.read(self, endianness)?;
+        chrono::DateTime::from_timestamp(secs, nanos)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "timestamp out of range"))
+    }
+
+    /// Read a `time::OffsetDateTime` in UTC in the given wire format.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the timestamp is out of range.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadTimeExt, TimestampFormat};
+    /// use std::io::Cursor;
+    /// use time::OffsetDateTime;
+    ///
+    /// let bytes = u32::MAX.to_be_bytes();
+    /// let time = Cursor::new(&bytes)
+    ///     .read_offset_date_time(TimestampFormat::UnixSecondsU32, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(time.year(), 2106);
+    /// ```
+    #[cfg(feature = "time")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_offset_date_time(
+        &mut self,
+        format: TimestampFormat,
+        endianness: Endianness,
+    ) -> Result<time::OffsetDateTime> {
+        let (secs, nanos) = format.read(self, endianness)?;
+        time::OffsetDateTime::from_unix_timestamp_nanos(
+            i128::from(secs) * 1_000_000_000 + i128::from(nanos),
+        )
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+}
+
+impl<T> ReadTimeExt for T where T: Read {}
+
+/// Writes of points in time and durations.
+pub trait WriteTimeExt: WriteNumExt {
+    /// Write a `SystemTime` as a `u64` of seconds since the Unix epoch in big endian.
+    ///
+    /// Sub-second precision is truncated.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the time is before the Unix epoch.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteTimeExt;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let mut bytes = Vec::new();
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_500);
+    /// bytes.write_system_time_secs_be(time).unwrap();
+    /// assert_eq!(bytes, 1_700_000_000u64.to_be_bytes());
+    ///
+    /// let time = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+    /// assert!(bytes.write_system_time_secs_be(time).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_system_time_secs_be(&mut self, time: SystemTime) -> Result<()> {
+        self.write_all(&secs_since_epoch(time)?.to_be_bytes())
+    }
+
+    /// Write a `SystemTime` as a `u64` of seconds since the Unix epoch in little endian.
+    ///
+    /// Sub-second precision is truncated.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the time is before the Unix epoch.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_system_time_secs_le(&mut self, time: SystemTime) -> Result<()> {
+        self.write_all(&secs_since_epoch(time)?.to_le_bytes())
+    }
+
+    /// Write a `Duration` as a `u64` of seconds followed by a `u32` of nanoseconds in big endian.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteTimeExt;
+    /// use std::time::Duration;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_duration_be(Duration::new(5, 500_000_000)).unwrap();
+    /// assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0, 5, 0x1D, 0xCD, 0x65, 0x00]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_duration_be(&mut self, duration: Duration) -> Result<()> {
+        self.write_all(&duration.as_secs().to_be_bytes())?;
+        self.write_all(&duration.subsec_nanos().to_be_bytes())
+    }
+
+    /// Write a `Duration` as a `u64` of seconds followed by a `u32` of nanoseconds in little endian.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_duration_le(&mut self, duration: Duration) -> Result<()> {
+        self.write_all(&duration.as_secs().to_le_bytes())?;
+        self.write_all(&duration.subsec_nanos().to_le_bytes())
+    }
+
+    /// Write a `chrono::DateTime<Utc>` in the given wire format.
+    ///
+    /// Precision beyond the resolution of the format is truncated.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`]
+    /// if the point in time cannot be represented in the format.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::DateTime;
+    /// use rw_exact_ext::{Endianness, TimestampFormat, WriteTimeExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// let time = DateTime::from_timestamp(2_147_483_648, 0).unwrap();
+    /// bytes
+    ///     .write_chrono_date_time(&time, TimestampFormat::UnixSecondsU32, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(bytes, [0x80, 0x00, 0x00, 0x00]);
+    ///
+    /// let time = DateTime::from_timestamp(-1, 0).unwrap();
+    /// assert!(bytes
+    ///     .write_chrono_date_time(&time, TimestampFormat::UnixSecondsU32, Endianness::Big)
+    ///     .is_err());
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_chrono_date_time(
+        &mut self,
+        time: &chrono::DateTime<chrono::Utc>,
+        format: TimestampFormat,
+        endianness: Endianness,
+    ) -> Result<()> {
+        format.write(
+            self,
+            endianness,
+            time.timestamp(),
+            time.timestamp_subsec_nanos() % 1_000_000_000,
+        )
+    }
+
+    /// Write a `time::OffsetDateTime` in the given wire format.
+    ///
+    /// Precision beyond the resolution of the format is truncated.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`]
+    /// if the point in time cannot be represented in the format.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, TimestampFormat, WriteTimeExt};
+    /// use time::OffsetDateTime;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes
+    ///     .write_offset_date_time(
+    ///         &OffsetDateTime::UNIX_EPOCH,
+    ///         TimestampFormat::FileTime,
+    ///         Endianness::Little,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(bytes, 116_444_736_000_000_000u64.to_le_bytes());
+    /// ```
+    #[cfg(feature = "time")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_offset_date_time(
+        &mut self,
+        time: &time::OffsetDateTime,
+        format: TimestampFormat,
+        endianness: Endianness,
+    ) -> Result<()> {
+        format.write(self, endianness, time.unix_timestamp(), time.nanosecond())
+    }
+}
+
+impl<T> WriteTimeExt for T where T: Write {}
+
+fn system_time_from_secs(secs: u64) -> Result<SystemTime> {
+    SystemTime::UNIX_EPOCH
+        .checked_add(Duration::from_secs(secs))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "system time out of range"))
+}
+
+fn secs_since_epoch(time: SystemTime) -> Result<u64> {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .map_err(|error| Error::new(ErrorKind::InvalidInput, error))
+}
+
+fn duration_from_parts(secs: u64, nanos: u32) -> Result<Duration> {
+    if nanos >= 1_000_000_000 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "nanoseconds exceed one second",
+        ));
+    }
+
+    Ok(Duration::new(secs, nanos))
+}
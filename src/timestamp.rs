@@ -0,0 +1,131 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{Endianness, FromWireBytes, ToWireBytes};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_FILETIME_TICK: u32 = 100;
+const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+const FILETIME_UNIX_EPOCH_SECS: i64 = 11_644_473_600;
+
+/// Wire encodings of points in time.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimestampFormat {
+    /// Seconds since the Unix epoch as `u32`.
+    ///
+    /// Covers the years 1970 to 2106.
+    UnixSecondsU32,
+    /// Seconds since the Unix epoch as `u64`.
+    UnixSecondsU64,
+    /// Milliseconds since the Unix epoch as `i64`.
+    ///
+    /// Negative values denote points in time before the Unix epoch.
+    UnixMillisI64,
+    /// 100-nanosecond intervals since 1601-01-01 as `u64`, as used by Windows' `FILETIME`.
+    FileTime,
+}
+
+impl TimestampFormat {
+    /// Read a timestamp as seconds and nanoseconds since the Unix epoch.
+    pub(crate) fn read<R>(self, reader: &mut R, endianness: Endianness) -> Result<(i64, u32)>
+    where
+        R: Read + ?Sized,
+    {
+        match self {
+            Self::UnixSecondsU32 => {
+                let mut bytes = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                let secs = u32::from_wire_bytes(bytes, endianness);
+                Ok((secs.into(), 0))
+            }
+            Self::UnixSecondsU64 => {
+                let secs = read_u64(reader, endianness)?;
+                i64::try_from(secs)
+                    .map(|secs| (secs, 0))
+                    .map_err(|_| out_of_range(ErrorKind::InvalidData))
+            }
+            Self::UnixMillisI64 => {
+                let millis = read_u64(reader, endianness)?.cast_signed();
+                let nanos = u32::try_from(millis.rem_euclid(1000))
+                    .expect("remainder should be less than 1000")
+                    * NANOS_PER_MILLI;
+                Ok((millis.div_euclid(1000), nanos))
+            }
+            Self::FileTime => {
+                let ticks = read_u64(reader, endianness)?;
+                let secs = i64::try_from(ticks / FILETIME_TICKS_PER_SEC)
+                    .expect("FILETIME seconds should fit into i64")
+                    - FILETIME_UNIX_EPOCH_SECS;
+                let nanos = u32::try_from(ticks % FILETIME_TICKS_PER_SEC)
+                    .expect("remainder should fit into u32")
+                    * NANOS_PER_FILETIME_TICK;
+                Ok((secs, nanos))
+            }
+        }
+    }
+
+    /// Write a timestamp given as seconds and nanoseconds since the Unix epoch.
+    ///
+    /// Precision beyond the resolution of the format is truncated.
+    pub(crate) fn write<W>(
+        self,
+        writer: &mut W,
+        endianness: Endianness,
+        secs: i64,
+        nanos: u32,
+    ) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        debug_assert!(nanos < NANOS_PER_SEC);
+
+        match self {
+            Self::UnixSecondsU32 => {
+                let secs =
+                    u32::try_from(secs).map_err(|_| out_of_range(ErrorKind::InvalidInput))?;
+                writer.write_all(&secs.to_wire_bytes(endianness))
+            }
+            Self::UnixSecondsU64 => {
+                let secs =
+                    u64::try_from(secs).map_err(|_| out_of_range(ErrorKind::InvalidInput))?;
+                write_u64(writer, endianness, secs)
+            }
+            Self::UnixMillisI64 => {
+                let millis = secs
+                    .checked_mul(1000)
+                    .and_then(|millis| millis.checked_add((nanos / NANOS_PER_MILLI).into()))
+                    .ok_or_else(|| out_of_range(ErrorKind::InvalidInput))?;
+                write_u64(writer, endianness, millis.cast_unsigned())
+            }
+            Self::FileTime => {
+                let ticks = secs
+                    .checked_add(FILETIME_UNIX_EPOCH_SECS)
+                    .and_then(|secs| u64::try_from(secs).ok())
+                    .and_then(|secs| secs.checked_mul(FILETIME_TICKS_PER_SEC))
+                    .and_then(|ticks| ticks.checked_add((nanos / NANOS_PER_FILETIME_TICK).into()))
+                    .ok_or_else(|| out_of_range(ErrorKind::InvalidInput))?;
+                write_u64(writer, endianness, ticks)
+            }
+        }
+    }
+}
+
+fn read_u64<R>(reader: &mut R, endianness: Endianness) -> Result<u64>
+where
+    R: Read + ?Sized,
+{
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_wire_bytes(bytes, endianness))
+}
+
+fn write_u64<W>(writer: &mut W, endianness: Endianness, value: u64) -> Result<()>
+where
+    W: Write + ?Sized,
+{
+    writer.write_all(&value.to_wire_bytes(endianness))
+}
+
+fn out_of_range(kind: ErrorKind) -> Error {
+    Error::new(kind, "timestamp out of range")
+}
@@ -0,0 +1,175 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{check_size, fill_or_eof, fill_vec, Endianness};
+
+/// Layout of type-length-value records.
+///
+/// Each record consists of a tag of `tag_size` bytes, the length of the value in bytes
+/// encoded in `len_size` bytes, and the value itself.
+/// Tag and length are unsigned integers of up to 8 bytes in the byte order `endianness`.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, ReadBytesExt, TlvFormat, WriteBytesExt};
+/// use std::io::{Cursor, Result};
+///
+/// let format = TlvFormat::new(2, 1, Endianness::Big);
+/// let mut bytes = Vec::new();
+/// bytes.write_tlv(format, 0x0102, &[0xAB, 0xCD]).unwrap();
+/// bytes.write_tlv(format, 0x0304, &[]).unwrap();
+/// assert_eq!(bytes, [0x01, 0x02, 0x02, 0xAB, 0xCD, 0x03, 0x04, 0x00]);
+///
+/// let records: Vec<(u64, Vec<u8>)> = Cursor::new(&bytes)
+///     .read_tlvs(format, None)
+///     .collect::<Result<_>>()
+///     .unwrap();
+/// assert_eq!(records, [(0x0102, vec![0xAB, 0xCD]), (0x0304, vec![])]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TlvFormat {
+    /// Size of the tag in bytes.
+    pub tag_size: usize,
+    /// Size of the length in bytes.
+    pub len_size: usize,
+    /// Byte order of tag and length.
+    pub endianness: Endianness,
+}
+
+impl TlvFormat {
+    /// One-byte tags and one-byte lengths.
+    pub const U8: Self = Self::new(1, 1, Endianness::Big);
+
+    /// Create a new format with the given tag and length sizes in bytes.
+    #[must_use]
+    pub const fn new(tag_size: usize, len_size: usize, endianness: Endianness) -> Self {
+        Self {
+            tag_size,
+            len_size,
+            endianness,
+        }
+    }
+
+    /// Read a record, or return `Ok(None)` if the stream ends before the first byte of the tag.
+    pub(crate) fn read<R>(
+        self,
+        reader: &mut R,
+        max_len: Option<usize>,
+    ) -> Result<Option<(u64, Vec<u8>)>>
+    where
+        R: Read + ?Sized,
+    {
+        let mut tag = [0; 8];
+        let mut len = [0; 8];
+        let tag = &mut tag[..check_field_size(self.tag_size)?];
+        let len = &mut len[..check_field_size(self.len_size)?];
+
+        if !fill_or_eof(reader, tag)? {
+            return Ok(None);
+        }
+
+        reader.read_exact(len)?;
+        let len = usize::try_from(self.decode(len))
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        check_size(len, max_len)?;
+        let mut value = Vec::new();
+        fill_vec(reader, &mut value, len)?;
+        Ok(Some((self.decode(tag), value)))
+    }
+
+    /// Write a record.
+    pub(crate) fn write<W>(self, writer: &mut W, tag: u64, value: &[u8]) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        let mut header = self.encode(tag, self.tag_size)?;
+        header.extend(self.encode(value.len() as u64, self.len_size)?);
+        writer.write_all(&header)?;
+        writer.write_all(value)
+    }
+
+    fn decode(self, bytes: &[u8]) -> u64 {
+        if self.endianness.is_big() {
+            bytes
+                .iter()
+                .fold(0, |value, &byte| value << 8 | u64::from(byte))
+        } else {
+            bytes
+                .iter()
+                .rev()
+                .fold(0, |value, &byte| value << 8 | u64::from(byte))
+        }
+    }
+
+    fn encode(self, value: u64, size: usize) -> Result<Vec<u8>> {
+        let bits = 8 * check_field_size(size)?;
+
+        if bits < 64 && value >> bits != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("TLV field value {value} does not fit into {size} bytes"),
+            ));
+        }
+
+        Ok(if self.endianness.is_big() {
+            value.to_be_bytes()[8 - size..].to_vec()
+        } else {
+            value.to_le_bytes()[..size].to_vec()
+        })
+    }
+}
+
+/// An iterator reading type-length-value records until the end of the underlying stream.
+///
+/// See [`ReadBytesExt::read_tlvs`](crate::ReadBytesExt::read_tlvs).
+#[derive(Debug)]
+pub struct Tlvs<'a, R>
+where
+    R: ?Sized,
+{
+    reader: &'a mut R,
+    format: TlvFormat,
+    max_len: Option<usize>,
+    done: bool,
+}
+
+impl<'a, R> Tlvs<'a, R>
+where
+    R: ?Sized,
+{
+    pub(crate) fn new(reader: &'a mut R, format: TlvFormat, max_len: Option<usize>) -> Self {
+        Self {
+            reader,
+            format,
+            max_len,
+            done: false,
+        }
+    }
+}
+
+impl<R> Iterator for Tlvs<'_, R>
+where
+    R: Read + ?Sized,
+{
+    type Item = Result<(u64, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.format.read(self.reader, self.max_len).transpose();
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+fn check_field_size(size: usize) -> Result<usize> {
+    if !(1..=8).contains(&size) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid TLV field size: {size}"),
+        ));
+    }
+
+    Ok(size)
+}
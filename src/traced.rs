@@ -0,0 +1,265 @@
+use std::fmt::Write as _;
+use std::io::{Read, Result, Write};
+
+/// Default amount of bytes shown in the hex preview of each operation.
+const DEFAULT_PREVIEW_LEN: usize = 32;
+
+/// Emit a debug event with the target `rw_exact_ext`, preferring `tracing` over `log`.
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!(target: "rw_exact_ext", $($arg)*);
+        #[cfg(all(feature = "log", not(feature = "tracing")))]
+        ::log::debug!(target: "rw_exact_ext", $($arg)*);
+    }};
+}
+
+/// A reader that emits a debug event for every read made through it.
+///
+/// Each event includes the offset of the read relative to the creation of the traced reader,
+/// the requested size, the amount of bytes read, and a hex preview of the bytes.
+/// Events are emitted with the target `rw_exact_ext` through `tracing` if the feature `tracing`
+/// is enabled, or through `log` otherwise.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{ReadNumExt, TracedReader};
+///
+/// let bytes = [0x01, 0xAC, 0x02];
+/// let mut reader = TracedReader::new(&bytes[..]).with_preview_len(16);
+/// // Emits "read_exact at offset 0: requested 1 bytes: 01".
+/// assert!(reader.read_bool().unwrap());
+/// assert_eq!(reader.read_varint_u64().unwrap(), 300);
+/// assert_eq!(reader.offset(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TracedReader<R> {
+    reader: R,
+    offset: u64,
+    preview_len: usize,
+}
+
+impl<R> TracedReader<R> {
+    /// Create a new traced reader wrapping `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            preview_len: DEFAULT_PREVIEW_LEN,
+        }
+    }
+
+    /// Set the maximum amount of bytes shown in the hex preview, which defaults to 32.
+    #[must_use]
+    pub const fn with_preview_len(mut self, preview_len: usize) -> Self {
+        self.preview_len = preview_len;
+        self
+    }
+
+    /// Return the amount of bytes read so far.
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Return a reference to the underlying reader.
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Return a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> Read for TracedReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let offset = self.offset;
+        let requested = buf.len();
+
+        match self.reader.read(buf) {
+            Ok(read) => {
+                self.offset += read as u64;
+                debug!(
+                    "read at offset {offset}: requested {requested} bytes, read {read} bytes: {}",
+                    hex_preview(&buf[..read], self.preview_len)
+                );
+                Ok(read)
+            }
+            Err(error) => {
+                debug!("read at offset {offset}: requested {requested} bytes, failed: {error}");
+                Err(error)
+            }
+        }
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        let offset = self.offset;
+        let requested = buf.len();
+
+        match self.reader.read_exact(buf) {
+            Ok(()) => {
+                self.offset += requested as u64;
+                debug!(
+                    "read_exact at offset {offset}: requested {requested} bytes: {}",
+                    hex_preview(buf, self.preview_len)
+                );
+                Ok(())
+            }
+            Err(error) => {
+                debug!(
+                    "read_exact at offset {offset}: requested {requested} bytes, failed: {error}"
+                );
+                Err(error)
+            }
+        }
+    }
+}
+
+/// A writer that emits a debug event for every write and flush made through it.
+///
+/// See [`TracedReader`] for the contents of the events.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{TracedWriter, WriteNumExt};
+///
+/// let mut writer = TracedWriter::new(Vec::new());
+/// // Emits "write_all at offset 0: requested 2 bytes: ac 02".
+/// writer.write_varint_u64(300).unwrap();
+/// assert_eq!(writer.offset(), 2);
+/// assert_eq!(writer.into_inner(), [0xAC, 0x02]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TracedWriter<W> {
+    writer: W,
+    offset: u64,
+    preview_len: usize,
+}
+
+impl<W> TracedWriter<W> {
+    /// Create a new traced writer wrapping `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            offset: 0,
+            preview_len: DEFAULT_PREVIEW_LEN,
+        }
+    }
+
+    /// Set the maximum amount of bytes shown in the hex preview, which defaults to 32.
+    #[must_use]
+    pub const fn with_preview_len(mut self, preview_len: usize) -> Self {
+        self.preview_len = preview_len;
+        self
+    }
+
+    /// Return the amount of bytes written so far.
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Return a reference to the underlying writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Return a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> Write for TracedWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let offset = self.offset;
+        let requested = buf.len();
+
+        match self.writer.write(buf) {
+            Ok(written) => {
+                self.offset += written as u64;
+                debug!(
+                    "write at offset {offset}: requested {requested} bytes, wrote {written} bytes: {}",
+                    hex_preview(&buf[..written], self.preview_len)
+                );
+                Ok(written)
+            }
+            Err(error) => {
+                debug!("write at offset {offset}: requested {requested} bytes, failed: {error}");
+                Err(error)
+            }
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        let offset = self.offset;
+        let requested = buf.len();
+
+        match self.writer.write_all(buf) {
+            Ok(()) => {
+                self.offset += requested as u64;
+                debug!(
+                    "write_all at offset {offset}: requested {requested} bytes: {}",
+                    hex_preview(buf, self.preview_len)
+                );
+                Ok(())
+            }
+            Err(error) => {
+                debug!(
+                    "write_all at offset {offset}: requested {requested} bytes, failed: {error}"
+                );
+                Err(error)
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let result = self.writer.flush();
+
+        match &result {
+            Ok(()) => debug!("flush at offset {}", self.offset),
+            Err(error) => debug!("flush at offset {}, failed: {error}", self.offset),
+        }
+
+        result
+    }
+}
+
+/// Format up to `len` bytes as space-separated hex digits, marking omitted bytes with an ellipsis.
+fn hex_preview(bytes: &[u8], len: usize) -> String {
+    let mut preview = String::with_capacity(3 * bytes.len().min(len) + 3);
+
+    for (index, byte) in bytes.iter().take(len).enumerate() {
+        if index > 0 {
+            preview.push(' ');
+        }
+
+        let _ = write!(preview, "{byte:02x}");
+    }
+
+    if bytes.len() > len {
+        if !preview.is_empty() {
+            preview.push(' ');
+        }
+
+        let _ = write!(preview, "… ({} more)", bytes.len() - len);
+    }
+
+    preview
+}
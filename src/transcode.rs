@@ -0,0 +1,147 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::{Endianness, FixedEncodedLen, ReadFrom, WriteTo};
+
+/// Amount of values converted at once.
+#[cfg(not(feature = "rayon"))]
+const CHUNK_VALUES: usize = 4096;
+/// Amount of values converted at once, which are split across threads.
+#[cfg(feature = "rayon")]
+const CHUNK_VALUES: usize = 256 * 1024;
+/// Minimum amount of values converted per thread.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_VALUES: usize = 4096;
+
+/// Read `count` numbers in the byte order `from` and write them in the byte order `to`.
+///
+/// The numbers are processed in chunks of [`FixedEncodedLen::ENCODED_LEN`] bytes per value,
+/// so each chunk is read with a single call to [`Read::read_exact`] and written with a single call
+/// to [`Write::write_all`].
+/// If the feature `rayon` is enabled, the values of large chunks are converted in parallel.
+///
+/// # Errors
+/// Returns any I/O error of `reader` or `writer`.
+/// Returns an error of kind [`ErrorKind::InvalidInput`] before reading anything if the size of
+/// a chunk overflows `usize`, or after reading a chunk if the encoding of `T` does not match
+/// its [`FixedEncodedLen::ENCODED_LEN`].
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{transcode_nums, Endianness};
+///
+/// let big = [0x00, 0x00, 0x00, 0x01, 0x12, 0x34, 0x56, 0x78];
+/// let mut little = Vec::new();
+/// transcode_nums::<u32, _, _>(&mut &big[..], &mut little, 2, Endianness::Big, Endianness::Little)
+///     .unwrap();
+/// assert_eq!(little, [0x01, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12]);
+///
+/// // Values other than primitive numbers are transcoded by their fixed encoded length.
+/// let big = [0x12, 0x34, 0x01];
+/// let mut little = Vec::new();
+/// transcode_nums::<(u16, bool), _, _>(&mut &big[..], &mut little, 1, Endianness::Big, Endianness::Little)
+///     .unwrap();
+/// assert_eq!(little, [0x34, 0x12, 0x01]);
+/// ```
+pub fn transcode_nums<T, R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    count: usize,
+    from: Endianness,
+    to: Endianness,
+) -> Result<()>
+where
+    T: ReadFrom + WriteTo + FixedEncodedLen,
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+{
+    let chunk_size = T::ENCODED_LEN
+        .checked_mul(CHUNK_VALUES.min(count))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "encoded size too large"))?;
+    let mut input = vec![0; chunk_size];
+    let mut output = Vec::with_capacity(input.len());
+    let mut remaining = count;
+
+    while remaining > 0 {
+        let values = CHUNK_VALUES.min(remaining);
+        let input = &mut input[..values * T::ENCODED_LEN];
+        reader.read_exact(input)?;
+
+        convert::<T>(input, &mut output, values, from, to)?;
+        writer.write_all(&output)?;
+        remaining -= values;
+    }
+
+    Ok(())
+}
+
+/// Convert `values` values of `T` from `input` in the byte order `from` into `output` in the byte order `to`.
+#[cfg(not(feature = "rayon"))]
+fn convert<T>(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    values: usize,
+    from: Endianness,
+    to: Endianness,
+) -> Result<()>
+where
+    T: ReadFrom + WriteTo + FixedEncodedLen,
+{
+    let mut chunk = input;
+    output.clear();
+
+    for _ in 0..values {
+        T::read_from(&mut chunk, from)?.write_to(output, to)?;
+    }
+
+    if !chunk.is_empty() || output.len() != input.len() {
+        return Err(encoding_mismatch());
+    }
+
+    Ok(())
+}
+
+/// Convert `values` values of `T` from `input` in the byte order `from` into `output` in the byte order `to`,
+/// splitting the values across threads.
+#[cfg(feature = "rayon")]
+fn convert<T>(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    _values: usize,
+    from: Endianness,
+    to: Endianness,
+) -> Result<()>
+where
+    T: ReadFrom + WriteTo + FixedEncodedLen,
+{
+    use rayon::prelude::*;
+
+    output.clear();
+    output.resize(input.len(), 0);
+
+    if T::ENCODED_LEN == 0 {
+        return Ok(());
+    }
+
+    input
+        .par_chunks_exact(T::ENCODED_LEN)
+        .zip(output.par_chunks_exact_mut(T::ENCODED_LEN))
+        .with_min_len(PARALLEL_MIN_VALUES)
+        .try_for_each(|(mut source, mut target)| {
+            T::read_from(&mut source, from)?.write_to(&mut target, to)?;
+
+            if !source.is_empty() || !target.is_empty() {
+                return Err(encoding_mismatch());
+            }
+
+            Ok(())
+        })
+}
+
+/// Return an error of kind [`ErrorKind::InvalidInput`] for a value whose encoding does not match
+/// its fixed encoded length.
+fn encoding_mismatch() -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        "encoding differs from fixed encoded length",
+    )
+}
@@ -0,0 +1,156 @@
+use std::io::{Error, ErrorKind, Read, Result};
+use std::str::Utf8Error;
+
+use crate::fill_vec;
+
+/// A reader that validates the bytes read through it as UTF-8.
+///
+/// Multi-byte sequences may be split across reads. Invalid sequences result in an error of kind
+/// [`ErrorKind::InvalidData`] naming the byte offset of the sequence within the stream.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{ReadBytesExt, Utf8Reader};
+/// use std::io::{ErrorKind, Read};
+///
+/// let mut reader = Utf8Reader::new("Hi😀!".as_bytes());
+/// assert_eq!(reader.read_str_exact(2).unwrap(), "Hi");
+/// let mut rest = String::new();
+/// reader.read_to_string(&mut rest).unwrap();
+/// assert_eq!(rest, "😀!");
+///
+/// let mut reader = Utf8Reader::new(&b"ok\xFFno"[..]);
+/// let error = reader.read_vec_exact(5).unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::InvalidData);
+/// assert_eq!(error.to_string(), "invalid UTF-8 sequence at byte offset 2");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Utf8Reader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    incomplete: Vec<u8>,
+    position: u64,
+}
+
+impl<R> Utf8Reader<R> {
+    /// Create a new UTF-8 validating reader wrapping `reader`.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            incomplete: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Return the amount of bytes read so far.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Return a reference to the underlying reader.
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Return a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Validate `bytes`, which continue the stream at the current position.
+    fn validate(&mut self, bytes: &[u8]) -> Result<()> {
+        let start = self.position - self.incomplete.len() as u64;
+        self.position += bytes.len() as u64;
+
+        let result = if self.incomplete.is_empty() {
+            std::str::from_utf8(bytes)
+                .map(drop)
+                .map_err(|error| (error, bytes))
+        } else {
+            self.incomplete.extend_from_slice(bytes);
+            std::str::from_utf8(&self.incomplete)
+                .map(drop)
+                .map_err(|error| (error, self.incomplete.as_slice()))
+        };
+
+        match result {
+            Ok(()) => {
+                self.incomplete.clear();
+                Ok(())
+            }
+            Err((error, data)) if error.error_len().is_none() => {
+                self.incomplete = data[error.valid_up_to()..].to_vec();
+                Ok(())
+            }
+            Err((error, _)) => Err(invalid_sequence(start, error)),
+        }
+    }
+}
+
+impl<R> Utf8Reader<R>
+where
+    R: Read,
+{
+    /// Read a string of exactly `size` bytes and return it borrowed from the internal buffer.
+    ///
+    /// The string must start and end at character boundaries.
+    ///
+    /// # Errors
+    /// Returns any I/O error of the underlying reader, or an error of kind
+    /// [`ErrorKind::InvalidData`] if the bytes are not valid UTF-8.
+    pub fn read_str_exact(&mut self, size: usize) -> Result<&str> {
+        if !self.incomplete.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "incomplete UTF-8 sequence at byte offset {}",
+                    self.position - self.incomplete.len() as u64
+                ),
+            ));
+        }
+
+        let start = self.position;
+        self.buffer.clear();
+        fill_vec(&mut self.reader, &mut self.buffer, size)?;
+        self.position += size as u64;
+        std::str::from_utf8(&self.buffer).map_err(|error| invalid_sequence(start, error))
+    }
+}
+
+impl<R> Read for Utf8Reader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let read = self.reader.read(buf)?;
+
+        if read == 0 && !buf.is_empty() && !self.incomplete.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "incomplete UTF-8 sequence at byte offset {}",
+                    self.position - self.incomplete.len() as u64
+                ),
+            ));
+        }
+
+        self.validate(&buf[..read])?;
+        Ok(read)
+    }
+}
+
+fn invalid_sequence(start: u64, error: Utf8Error) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "invalid UTF-8 sequence at byte offset {}",
+            start + error.valid_up_to() as u64
+        ),
+    )
+}
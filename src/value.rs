@@ -0,0 +1,567 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::mem::MaybeUninit;
+use std::num::{Saturating, Wrapping};
+use std::ptr;
+
+use crate::{Endianness, FromWireBytes, ToWireBytes};
+
+/// A value that can be read from a byte stream.
+///
+/// Multi-byte numbers are decoded in the byte order passed to [`ReadFrom::read_from`].
+/// Composite values, such as tuples and arrays, read their elements in order.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, ReadValueExt};
+/// use std::io::Cursor;
+///
+/// let bytes = [0x00, 0x00, 0x01, 0x00, 0xAB, 0xCD, 0x01, 0x02, 0x03];
+/// let (id, flags, tag): (u32, u16, [u8; 3]) = Cursor::new(&bytes)
+///     .read_value(Endianness::Big)
+///     .unwrap();
+/// assert_eq!(id, 0x0100);
+/// assert_eq!(flags, 0xABCD);
+/// assert_eq!(tag, [0x01, 0x02, 0x03]);
+/// ```
+///
+/// With the feature `arbitrary-int` enabled, integers of the `arbitrary-int` crate are
+/// read and written as their underlying primitive type:
+/// ```
+/// # #[cfg(feature = "arbitrary-int")]
+/// # {
+/// use arbitrary_int::{u12, u4};
+/// use rw_exact_ext::{Endianness, ReadValueExt, WriteValueExt};
+/// use std::io::Cursor;
+///
+/// let (channel, sample): (u4, u12) = Cursor::new([0x0A, 0x0F, 0xFF])
+///     .read_value(Endianness::Big)
+///     .unwrap();
+/// assert_eq!(channel, u4::new(0xA));
+/// assert_eq!(sample, u12::new(0xFFF));
+///
+/// let mut bytes = Vec::new();
+/// bytes.write_value(&(channel, sample), Endianness::Little).unwrap();
+/// assert_eq!(bytes, [0x0A, 0xFF, 0x0F]);
+///
+/// assert!(Cursor::new([0x10])
+///     .read_value::<u4>(Endianness::Big)
+///     .is_err());
+/// # }
+/// ```
+pub trait ReadFrom: Sized {
+    /// Read a value from `reader`, decoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    #[allow(clippy::missing_errors_doc)]
+    fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+    where
+        R: Read + ?Sized;
+
+    /// Read an array of `SIZE` values from `reader`, decoding numbers in the given byte order.
+    ///
+    /// This is used by the implementation for `[Self; SIZE]`. The default implementation reads
+    /// the values one by one without allocating. Primitive numbers override it to read all
+    /// elements with a single call to [`Read::read_exact`].
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadFrom};
+    ///
+    /// let bytes = [0x00, 0x01, 0x00, 0x02];
+    /// let array: [u16; 2] = u16::read_array_from(&mut &bytes[..], Endianness::Big).unwrap();
+    /// assert_eq!(array, [1, 2]);
+    /// assert!(u16::read_array_from::<_, 3>(&mut &bytes[..], Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array_from<R, const SIZE: usize>(
+        reader: &mut R,
+        endianness: Endianness,
+    ) -> Result<[Self; SIZE]>
+    where
+        R: Read + ?Sized,
+    {
+        let mut array = PartialArray::<Self, SIZE>::new();
+
+        while array.initialized < SIZE {
+            array.push(Self::read_from(reader, endianness)?);
+        }
+
+        Ok(array.finish())
+    }
+
+    /// Read a value from the front of `bytes` and advance `bytes` past it,
+    /// decoding numbers in the given byte order.
+    ///
+    /// This is used by [`ByteReader::read_value`](crate::ByteReader::read_value).
+    /// The default implementation reads from `bytes` through [`ReadFrom::read_from`].
+    /// Numbers, booleans, arrays and tuples override it to decode directly from the slice,
+    /// without copying into an intermediate buffer.
+    ///
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if `bytes` is too short.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadFrom};
+    ///
+    /// let mut bytes = &[0x00, 0x01, 0x01, 0xFF][..];
+    /// let value = <(u16, bool)>::read_from_slice(&mut bytes, Endianness::Big).unwrap();
+    /// assert_eq!(value, (1, true));
+    /// assert_eq!(bytes, [0xFF]);
+    /// assert!(u16::read_from_slice(&mut bytes, Endianness::Big).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+        Self::read_from(bytes, endianness)
+    }
+}
+
+/// An array of which the first `initialized` elements have been initialized,
+/// which are dropped if the array is dropped before it is complete.
+struct PartialArray<T, const SIZE: usize> {
+    elements: [MaybeUninit<T>; SIZE],
+    initialized: usize,
+}
+
+impl<T, const SIZE: usize> PartialArray<T, SIZE> {
+    const fn new() -> Self {
+        Self {
+            elements: [const { MaybeUninit::uninit() }; SIZE],
+            initialized: 0,
+        }
+    }
+
+    fn push(&mut self, element: T) {
+        self.elements[self.initialized].write(element);
+        self.initialized += 1;
+    }
+
+    fn finish(self) -> [T; SIZE] {
+        assert_eq!(self.initialized, SIZE, "array should be complete");
+        let elements = ptr::addr_of!(self.elements).cast::<[T; SIZE]>();
+        std::mem::forget(self);
+        // SAFETY: All elements have been initialized, and `MaybeUninit<T>` has the same layout as `T`.
+        // The elements are not dropped by `self`, which has been forgotten.
+        unsafe { elements.read() }
+    }
+}
+
+impl<T, const SIZE: usize> Drop for PartialArray<T, SIZE> {
+    fn drop(&mut self) {
+        for element in &mut self.elements[..self.initialized] {
+            // SAFETY: The first `initialized` elements have been initialized and are dropped only here.
+            unsafe { element.assume_init_drop() };
+        }
+    }
+}
+
+/// Decode a value of a type implementing [`ReadFrom`] from `bytes` and require it to span all of them.
+///
+/// # Errors
+/// Returns any error of decoding, or an error of kind [`ErrorKind::InvalidData`]
+/// stating the amount of unread bytes if any bytes remain after the value.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{from_bytes_exact, Endianness};
+///
+/// let value: (u16, bool) = from_bytes_exact(&[0x12, 0x34, 0x01], Endianness::Big).unwrap();
+/// assert_eq!(value, (0x1234, true));
+///
+/// let error = from_bytes_exact::<u16>(&[0x12, 0x34, 0x01], Endianness::Big).unwrap_err();
+/// assert_eq!(error.to_string(), "trailing bytes after value of type u16: 1");
+/// ```
+pub fn from_bytes_exact<T>(mut bytes: &[u8], endianness: Endianness) -> Result<T>
+where
+    T: ReadFrom,
+{
+    let value = T::read_from(&mut bytes, endianness)?;
+
+    if !bytes.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "trailing bytes after value of type {}: {}",
+                std::any::type_name::<T>(),
+                bytes.len()
+            ),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// A value that can be written to a byte stream.
+///
+/// This is the counterpart of [`ReadFrom`] and produces the same layout.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, WriteValueExt};
+///
+/// let mut bytes = Vec::new();
+/// bytes
+///     .write_value(&(0x0100_u32, 0xABCD_u16, [0x01_u8, 0x02, 0x03]), Endianness::Little)
+///     .unwrap();
+/// assert_eq!(bytes, [0x00, 0x01, 0x00, 0x00, 0xCD, 0xAB, 0x01, 0x02, 0x03]);
+/// ```
+pub trait WriteTo {
+    /// Write the value to `writer`, encoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    #[allow(clippy::missing_errors_doc)]
+    fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+    where
+        W: Write + ?Sized;
+}
+
+/// A value whose encoding always has the same size.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::FixedEncodedLen;
+///
+/// assert_eq!(<(u32, u16, [u8; 8])>::ENCODED_LEN, 14);
+/// ```
+pub trait FixedEncodedLen {
+    /// Size of the encoding in bytes.
+    const ENCODED_LEN: usize;
+}
+
+/// A value whose encoded size can be computed before writing it.
+///
+/// The size is the exact amount of bytes [`WriteTo::write_to`] writes for the value,
+/// which allows to pre-allocate buffers, to write length prefixes and to validate size limits
+/// before serializing.
+/// Structs declared with [`byte_layout!`](crate::byte_layout) implement it automatically.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{EncodedLen, Endianness, WriteValueExt};
+///
+/// let value = (0x0100_u32, [true; 3]);
+/// let mut bytes = Vec::with_capacity(value.encoded_len());
+/// bytes.write_value(&value, Endianness::Big).unwrap();
+/// assert_eq!(bytes.len(), value.encoded_len());
+/// assert_eq!(value.encoded_len(), 7);
+/// ```
+pub trait EncodedLen {
+    /// Return the size of the encoding of the value in bytes.
+    fn encoded_len(&self) -> usize;
+}
+
+macro_rules! impl_num {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl ReadFrom for $type {
+                fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+                where
+                    R: Read + ?Sized,
+                {
+                    let mut bytes = [0; size_of::<Self>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(Self::from_wire_bytes(bytes, endianness))
+                }
+
+                fn read_array_from<R, const SIZE: usize>(
+                    reader: &mut R,
+                    endianness: Endianness,
+                ) -> Result<[Self; SIZE]>
+                where
+                    R: Read + ?Sized,
+                {
+                    let mut array = [Self::default(); SIZE];
+                    // SAFETY: Primitive numbers have no padding and every bit pattern is valid,
+                    // so the array can be viewed and written as plain bytes.
+                    let bytes = unsafe {
+                        std::slice::from_raw_parts_mut(
+                            array.as_mut_ptr().cast::<u8>(),
+                            size_of::<[Self; SIZE]>(),
+                        )
+                    };
+                    reader.read_exact(bytes)?;
+
+                    if !endianness.is_native() {
+                        for value in &mut array {
+                            let mut bytes = value.to_ne_bytes();
+                            bytes.reverse();
+                            *value = Self::from_ne_bytes(bytes);
+                        }
+                    }
+
+                    Ok(array)
+                }
+
+                fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+                    let (array, rest) = bytes.split_first_chunk().ok_or_else(slice_too_short)?;
+                    *bytes = rest;
+                    Ok(Self::from_wire_bytes(*array, endianness))
+                }
+            }
+
+            impl FixedEncodedLen for $type {
+                const ENCODED_LEN: usize = size_of::<Self>();
+            }
+
+            impl EncodedLen for $type {
+                fn encoded_len(&self) -> usize {
+                    Self::ENCODED_LEN
+                }
+            }
+
+            impl WriteTo for $type {
+                fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+                where
+                    W: Write + ?Sized,
+                {
+                    writer.write_all(&self.to_wire_bytes(endianness))
+                }
+            }
+        )*
+    };
+}
+
+impl_num!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl ReadFrom for bool {
+    fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+    where
+        R: Read + ?Sized,
+    {
+        u8::read_from(reader, endianness).map(|byte| byte != 0)
+    }
+
+    fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+        u8::read_from_slice(bytes, endianness).map(|byte| byte != 0)
+    }
+}
+
+impl FixedEncodedLen for bool {
+    const ENCODED_LEN: usize = 1;
+}
+
+impl EncodedLen for bool {
+    fn encoded_len(&self) -> usize {
+        Self::ENCODED_LEN
+    }
+}
+
+impl WriteTo for bool {
+    fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        u8::from(*self).write_to(writer, endianness)
+    }
+}
+
+macro_rules! impl_newtype {
+    ($(#[$attr:meta])* $wrapper:ident) => {
+        $(#[$attr])*
+        impl<T> ReadFrom for $wrapper<T>
+        where
+            T: ReadFrom,
+        {
+            fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+            where
+                R: Read + ?Sized,
+            {
+                T::read_from(reader, endianness).map($wrapper)
+            }
+
+            fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+                T::read_from_slice(bytes, endianness).map($wrapper)
+            }
+        }
+
+        impl<T> WriteTo for $wrapper<T>
+        where
+            T: WriteTo,
+        {
+            fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+            where
+                W: Write + ?Sized,
+            {
+                self.0.write_to(writer, endianness)
+            }
+        }
+
+        impl<T> FixedEncodedLen for $wrapper<T>
+        where
+            T: FixedEncodedLen,
+        {
+            const ENCODED_LEN: usize = T::ENCODED_LEN;
+        }
+
+        impl<T> EncodedLen for $wrapper<T>
+        where
+            T: EncodedLen,
+        {
+            fn encoded_len(&self) -> usize {
+                self.0.encoded_len()
+            }
+        }
+    };
+}
+
+impl_newtype!(
+    /// [`Wrapping`] is read and written like the wrapped value.
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt, WriteValueExt};
+    /// use std::io::Cursor;
+    /// use std::num::Wrapping;
+    ///
+    /// let mut sequence: Wrapping<u32> = Cursor::new([0xFF; 4])
+    ///     .read_value(Endianness::Big)
+    ///     .unwrap();
+    /// sequence += 1;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_value(&sequence, Endianness::Big).unwrap();
+    /// assert_eq!(bytes, [0x00; 4]);
+    /// ```
+    Wrapping
+);
+impl_newtype!(
+    /// [`Saturating`] is read and written like the wrapped value.
+    Saturating
+);
+
+/// Arrays read their elements in order, which includes nested arrays such as matrices.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, ReadValueExt};
+/// use std::io::Cursor;
+///
+/// let bytes: Vec<u8> = (0_u8..16)
+///     .flat_map(|index| f32::from(index).to_le_bytes())
+///     .collect();
+/// let matrix: [[f32; 4]; 4] = Cursor::new(&bytes).read_value(Endianness::Little).unwrap();
+/// assert_eq!(matrix[0], [0.0, 1.0, 2.0, 3.0]);
+/// assert_eq!(matrix[3], [12.0, 13.0, 14.0, 15.0]);
+/// ```
+impl<T, const SIZE: usize> ReadFrom for [T; SIZE]
+where
+    T: ReadFrom,
+{
+    fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+    where
+        R: Read + ?Sized,
+    {
+        T::read_array_from(reader, endianness)
+    }
+
+    fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+        let mut array = PartialArray::<T, SIZE>::new();
+
+        while array.initialized < SIZE {
+            array.push(T::read_from_slice(bytes, endianness)?);
+        }
+
+        Ok(array.finish())
+    }
+}
+
+impl<T, const SIZE: usize> FixedEncodedLen for [T; SIZE]
+where
+    T: FixedEncodedLen,
+{
+    const ENCODED_LEN: usize = T::ENCODED_LEN * SIZE;
+}
+
+impl<T, const SIZE: usize> EncodedLen for [T; SIZE]
+where
+    T: EncodedLen,
+{
+    fn encoded_len(&self) -> usize {
+        self.iter().map(EncodedLen::encoded_len).sum()
+    }
+}
+
+impl<T, const SIZE: usize> WriteTo for [T; SIZE]
+where
+    T: WriteTo,
+{
+    fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+    where
+        W: Write + ?Sized,
+    {
+        self.iter()
+            .try_for_each(|element| element.write_to(writer, endianness))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident),+) => {
+        impl<$($name),+> ReadFrom for ($($name,)+)
+        where
+            $($name: ReadFrom,)+
+        {
+            fn read_from<R>(reader: &mut R, endianness: Endianness) -> Result<Self>
+            where
+                R: Read + ?Sized,
+            {
+                Ok(($($name::read_from(reader, endianness)?,)+))
+            }
+
+            fn read_from_slice(bytes: &mut &[u8], endianness: Endianness) -> Result<Self> {
+                Ok(($($name::read_from_slice(bytes, endianness)?,)+))
+            }
+        }
+
+        impl<$($name),+> WriteTo for ($($name,)+)
+        where
+            $($name: WriteTo,)+
+        {
+            #[allow(non_snake_case)]
+            fn write_to<W>(&self, writer: &mut W, endianness: Endianness) -> Result<()>
+            where
+                W: Write + ?Sized,
+            {
+                let ($($name,)+) = self;
+                $($name.write_to(writer, endianness)?;)+
+                Ok(())
+            }
+        }
+
+        impl<$($name),+> FixedEncodedLen for ($($name,)+)
+        where
+            $($name: FixedEncodedLen,)+
+        {
+            const ENCODED_LEN: usize = 0 $(+ $name::ENCODED_LEN)+;
+        }
+
+        impl<$($name),+> EncodedLen for ($($name,)+)
+        where
+            $($name: EncodedLen,)+
+        {
+            #[allow(non_snake_case)]
+            fn encoded_len(&self) -> usize {
+                let ($($name,)+) = self;
+                0 $(+ $name.encoded_len())+
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Return an error of kind [`ErrorKind::UnexpectedEof`] for a slice that is too short for a value.
+fn slice_too_short() -> Error {
+    Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer")
+}
@@ -0,0 +1,690 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::iter;
+
+use crate::{
+    check_size, fill, EncodedLen, Endianness, ReadBytesExt, ReadFrom, ReadValues, WriteBytesExt,
+    WriteTo,
+};
+#[cfg(feature = "ndarray")]
+use crate::{FromWireBytes, ToWireBytes};
+
+/// Exact reads of typed values.
+pub trait ReadValueExt: ReadBytesExt {
+    /// Read a plain-old-data value with a single exact read.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadValueExt;
+    /// use std::io::Cursor;
+    /// use zerocopy::byteorder::big_endian::U16;
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42, 0x13, 0x37];
+    /// let pod: [U16; 3] = Cursor::new(&bytes).read_pod().unwrap();
+    /// assert_eq!(pod.map(U16::get), [0xABCD, 0xEF42, 0x1337]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "zerocopy")]
+    fn read_pod<T>(&mut self) -> Result<T>
+    where
+        T: zerocopy::FromBytes,
+    {
+        T::read_from_io(self)
+    }
+
+    /// Read `count` plain-old-data values with a single exact read.
+    ///
+    /// The values are read in their in-memory representation,
+    /// i.e. numbers are interpreted in native endianness.
+    /// Use e.g. [`u32::from_be`] on the elements to convert them from a different byte order.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if `T` is zero-sized.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::ReadValueExt;
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0xAB, 0xCD, 0xEF, 0x42, 0x13, 0x37];
+    /// let samples: Vec<u16> = Cursor::new(&bytes).read_pod_slice(3).unwrap();
+    /// let samples: Vec<u16> = samples.into_iter().map(u16::from_be).collect();
+    /// assert_eq!(samples, [0xABCD, 0xEF42, 0x1337]);
+    ///
+    /// let error = Cursor::new(&bytes).read_pod_slice::<()>(3).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "bytemuck")]
+    fn read_pod_slice<T>(&mut self, count: usize) -> Result<Vec<T>>
+    where
+        T: bytemuck::Pod,
+    {
+        check_element_size::<T>()?;
+        let mut values = vec![T::zeroed(); count];
+        self.read_exact(bytemuck::cast_slice_mut(&mut values))?;
+        Ok(values)
+    }
+
+    /// Read a value of a type implementing [`ReadFrom`], decoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x12, 0x34, 0x01, 0xAB, 0xCD];
+    /// let mut cursor = Cursor::new(&bytes);
+    /// let value: (u16, bool) = cursor.read_value(Endianness::Little).unwrap();
+    /// assert_eq!(value, (0x3412, true));
+    /// assert!(cursor.read_value::<u32>(Endianness::Little).is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_value<T>(&mut self, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        T::read_from(self, endianness)
+    }
+
+    /// Read a value of a type implementing [`ReadFrom`] and require the stream to end after it.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if any bytes remain after the value.
+    /// See [`from_bytes_exact`](crate::from_bytes_exact) for in-memory bytes.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0x12, 0x34, 0x01];
+    /// let value: (u16, bool) = Cursor::new(&bytes).read_value_to_end(Endianness::Big).unwrap();
+    /// assert_eq!(value, (0x1234, true));
+    ///
+    /// let error = Cursor::new(&bytes).read_value_to_end::<u16>(Endianness::Big).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_value_to_end<T>(&mut self, endianness: Endianness) -> Result<T>
+    where
+        T: ReadFrom,
+    {
+        let value = T::read_from(self, endianness)?;
+
+        if fill(self, &mut [0])? > 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "trailing bytes after value of type {}",
+                    std::any::type_name::<T>()
+                ),
+            ));
+        }
+
+        Ok(value)
+    }
+
+    /// Read `count` values of a type implementing [`ReadFrom`] and collect them into `C`.
+    ///
+    /// Reading stops at the first error.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::collections::BTreeMap;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x01, 0x00, 0x0A, 0x02, 0x00, 0x14];
+    /// let map: BTreeMap<u8, u16> = Cursor::new(&bytes)
+    ///     .read_collect(2, Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(map, BTreeMap::from([(1, 10), (2, 20)]));
+    ///
+    /// assert!(Cursor::new(&bytes)
+    ///     .read_collect::<(u8, u16), Vec<_>>(3, Endianness::Big)
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_collect<T, C>(&mut self, count: usize, endianness: Endianness) -> Result<C>
+    where
+        T: ReadFrom,
+        C: FromIterator<T>,
+    {
+        iter::repeat_with(|| T::read_from(self, endianness))
+            .take(count)
+            .collect()
+    }
+
+    /// Return an iterator reading values of a type implementing [`ReadFrom`] until the end of the stream.
+    ///
+    /// The iterator ends if the stream ends exactly after a value.
+    /// If the stream ends within a value, the iterator yields an error of kind
+    /// [`ErrorKind::UnexpectedEof`] and ends afterwards.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::io::{Cursor, ErrorKind, Result};
+    ///
+    /// let bytes = [0x00, 0x01, 0x00, 0x02];
+    /// let values: Vec<u16> = Cursor::new(&bytes)
+    ///     .read_values_to_end(Endianness::Big)
+    ///     .collect::<Result<_>>()
+    ///     .unwrap();
+    /// assert_eq!(values, [1, 2]);
+    ///
+    /// let mut cursor = Cursor::new(&bytes[..3]);
+    /// let mut values = cursor.read_values_to_end::<u16>(Endianness::Big);
+    /// assert_eq!(values.next().unwrap().unwrap(), 1);
+    /// assert_eq!(values.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    /// assert!(values.next().is_none());
+    /// ```
+    fn read_values_to_end<T>(&mut self, endianness: Endianness) -> ReadValues<'_, Self, T>
+    where
+        T: ReadFrom,
+    {
+        ReadValues::new(self, endianness)
+    }
+
+    /// Read a two-dimensional array of `rows` × `cols` numbers in big endian, in row-major order.
+    ///
+    /// The elements are read with a single call to [`Read::read_exact`] and byte-swapped in place,
+    /// which is done in parallel for large arrays if the feature `rayon` is enabled.
+    /// Since each element is byte-swapped as a whole, only primitive numbers are supported.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the amount of bytes overflows `usize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::array;
+    /// use rw_exact_ext::ReadValueExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, 0x00, 0x06];
+    /// let grid = Cursor::new(&bytes).read_array2_be::<u16>((2, 3)).unwrap();
+    /// assert_eq!(grid, array![[1, 2, 3], [4, 5, 6]]);
+    /// assert!(Cursor::new(&bytes).read_array2_be::<u16>((3, 3)).is_err());
+    /// ```
+    #[cfg(feature = "ndarray")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array2_be<T>(&mut self, shape: (usize, usize)) -> Result<ndarray::Array2<T>>
+    where
+        T: bytemuck::Pod + FromWireBytes,
+    {
+        read_array2(self, shape, Endianness::Big)
+    }
+
+    /// Read a two-dimensional array of `rows` × `cols` numbers in little endian, in row-major order.
+    ///
+    /// The elements are read with a single call to [`Read::read_exact`] and byte-swapped in place,
+    /// which is done in parallel for large arrays if the feature `rayon` is enabled.
+    /// Since each element is byte-swapped as a whole, only primitive numbers are supported.
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the amount of bytes overflows `usize`.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::array;
+    /// use rw_exact_ext::ReadValueExt;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes: Vec<u8> = [1.5_f64, -2.0, 0.25, 8.0]
+    ///     .iter()
+    ///     .flat_map(|value| value.to_le_bytes())
+    ///     .collect();
+    /// let grid = Cursor::new(&bytes).read_array2_le::<f64>((2, 2)).unwrap();
+    /// assert_eq!(grid, array![[1.5, -2.0], [0.25, 8.0]]);
+    /// ```
+    #[cfg(feature = "ndarray")]
+    #[allow(clippy::missing_errors_doc)]
+    fn read_array2_le<T>(&mut self, shape: (usize, usize)) -> Result<ndarray::Array2<T>>
+    where
+        T: bytemuck::Pod + FromWireBytes,
+    {
+        read_array2(self, shape, Endianness::Little)
+    }
+
+    /// Read `count` key/value pairs of types implementing [`ReadFrom`] into the map `M`.
+    ///
+    /// Each key is directly followed by its value.
+    /// Duplicate keys are handled by the [`FromIterator`] implementation of `M`,
+    /// which usually keeps the last value.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::collections::HashMap;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x01, 0x00, 0x0A, 0x02, 0x00, 0x14];
+    /// let map: HashMap<u8, u16> = Cursor::new(&bytes).read_map(2, Endianness::Big).unwrap();
+    /// assert_eq!(map, HashMap::from([(1, 10), (2, 20)]));
+    /// assert!(Cursor::new(&bytes)
+    ///     .read_map::<u8, u16, HashMap<_, _>>(3, Endianness::Big)
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_map<K, V, M>(&mut self, count: usize, endianness: Endianness) -> Result<M>
+    where
+        K: ReadFrom,
+        V: ReadFrom,
+        M: FromIterator<(K, V)>,
+    {
+        self.read_collect(count, endianness)
+    }
+
+    /// Read key/value pairs into the map `M`, prefixed with their count as `P` in the given byte order.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the count does not fit into `usize`
+    /// or exceeds `max_count`.
+    ///
+    /// For further semantics please refer to [`ReadValueExt::read_map`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::collections::BTreeMap;
+    /// use std::io::Cursor;
+    ///
+    /// let bytes = [0x02, 0x01, 0x0A, 0x00, 0x02, 0x14, 0x00];
+    /// let map: BTreeMap<u8, u16> = Cursor::new(&bytes)
+    ///     .read_map_prefixed::<u8, _, _, _>(Endianness::Little, None)
+    ///     .unwrap();
+    /// assert_eq!(map, BTreeMap::from([(1, 10), (2, 20)]));
+    /// assert!(Cursor::new(&bytes)
+    ///     .read_map_prefixed::<u8, u8, u16, BTreeMap<_, _>>(Endianness::Little, Some(1))
+    ///     .is_err());
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_map_prefixed<P, K, V, M>(
+        &mut self,
+        endianness: Endianness,
+        max_count: Option<usize>,
+    ) -> Result<M>
+    where
+        P: ReadFrom + TryInto<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+        K: ReadFrom,
+        V: ReadFrom,
+        M: FromIterator<(K, V)>,
+    {
+        let count = P::read_from(self, endianness)?
+            .try_into()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        check_size(count, max_count)?;
+        self.read_map(count, endianness)
+    }
+
+    /// Read `count` values of a type implementing [`ReadFrom`] into a `Vec`.
+    ///
+    /// The vector is allocated up front without aborting on allocation failure.
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if `count` exceeds `max_count`,
+    /// or of kind [`ErrorKind::OutOfMemory`] if the vector cannot be allocated.
+    ///
+    /// For further semantics please refer to [`Read::read_exact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, ReadValueExt};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let bytes = [0x01, 0x00, 0x0A, 0x00, 0x00, 0x14];
+    /// let records: Vec<(bool, u16)> = Cursor::new(&bytes)
+    ///     .read_values(2, Endianness::Big, None)
+    ///     .unwrap();
+    /// assert_eq!(records, [(true, 10), (false, 20)]);
+    ///
+    /// let error = Cursor::new(&bytes)
+    ///     .read_values::<(bool, u16)>(2, Endianness::Big, Some(1))
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    ///
+    /// let error = Cursor::new(&bytes)
+    ///     .read_values::<u64>(usize::MAX, Endianness::Big, None)
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::OutOfMemory);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn read_values<T>(
+        &mut self,
+        count: usize,
+        endianness: Endianness,
+        max_count: Option<usize>,
+    ) -> Result<Vec<T>>
+    where
+        T: ReadFrom,
+    {
+        check_size(count, max_count)?;
+        let mut values = Vec::new();
+        values
+            .try_reserve_exact(count)
+            .map_err(|error| Error::new(ErrorKind::OutOfMemory, error))?;
+
+        for _ in 0..count {
+            values.push(T::read_from(self, endianness)?);
+        }
+
+        Ok(values)
+    }
+}
+
+impl<T> ReadValueExt for T where T: Read {}
+
+/// Writes of typed values.
+pub trait WriteValueExt: WriteBytesExt {
+    /// Write a plain-old-data value with a single [`Write::write_all`].
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::WriteValueExt;
+    /// use zerocopy::byteorder::big_endian::U16;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_pod(&[0xABCD, 0xEF42, 0x1337].map(U16::new)).unwrap();
+    /// assert_eq!(bytes, [0xAB, 0xCD, 0xEF, 0x42, 0x13, 0x37]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    #[cfg(feature = "zerocopy")]
+    fn write_pod<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: zerocopy::IntoBytes + zerocopy::Immutable + ?Sized,
+    {
+        self.write_all(value.as_bytes())
+    }
+
+    /// Write a value of a type implementing [`WriteTo`], encoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteValueExt};
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_value(&[0x1234_u16, 0x5678], Endianness::Big).unwrap();
+    /// assert_eq!(bytes, [0x12, 0x34, 0x56, 0x78]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_value<T>(&mut self, value: &T, endianness: Endianness) -> Result<()>
+    where
+        T: WriteTo + ?Sized,
+    {
+        value.write_to(self, endianness)
+    }
+
+    /// Write a two-dimensional array of numbers in big endian, in row-major order.
+    ///
+    /// The elements are written with a single call to [`Write::write_all`].
+    /// If the feature `rayon` is enabled, large arrays are byte-swapped in parallel.
+    /// Since each element is byte-swapped as a whole, only primitive numbers are supported.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::array;
+    /// use rw_exact_ext::WriteValueExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_array2_be(&array![[1_u16, 2], [3, 4]]).unwrap();
+    /// assert_eq!(bytes, [0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04]);
+    /// ```
+    #[cfg(feature = "ndarray")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_array2_be<S, T>(&mut self, array: &ndarray::ArrayBase<S, ndarray::Ix2>) -> Result<()>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: bytemuck::Pod + ToWireBytes,
+    {
+        write_array2(self, array, Endianness::Big)
+    }
+
+    /// Write a two-dimensional array of numbers in little endian, in row-major order.
+    ///
+    /// The elements are written with a single call to [`Write::write_all`].
+    /// If the feature `rayon` is enabled, large arrays are byte-swapped in parallel.
+    /// Since each element is byte-swapped as a whole, only primitive numbers are supported.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::array;
+    /// use rw_exact_ext::WriteValueExt;
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.write_array2_le(&array![[1_u16, 2], [3, 4]].t()).unwrap();
+    /// assert_eq!(bytes, [0x01, 0x00, 0x03, 0x00, 0x02, 0x00, 0x04, 0x00]);
+    /// ```
+    #[cfg(feature = "ndarray")]
+    #[allow(clippy::missing_errors_doc)]
+    fn write_array2_le<S, T>(&mut self, array: &ndarray::ArrayBase<S, ndarray::Ix2>) -> Result<()>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: bytemuck::Pod + ToWireBytes,
+    {
+        write_array2(self, array, Endianness::Little)
+    }
+
+    /// Write a value of a type implementing [`WriteTo`] with a single call to [`Write::write_all`],
+    /// encoding numbers in the given byte order.
+    ///
+    /// The value is serialized into a buffer pre-allocated with its [`EncodedLen::encoded_len`] first.
+    /// This avoids one small write per field on unbuffered writers like sockets,
+    /// without wrapping them in a [`BufWriter`](std::io::BufWriter).
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, MeteredWriter, WriteValueExt};
+    ///
+    /// let mut writer = MeteredWriter::new(Vec::new());
+    /// writer
+    ///     .write_value_coalesced(&(0x1234_u16, 0x56_u8, [0x78_u8; 2]), Endianness::Big)
+    ///     .unwrap();
+    /// assert_eq!(writer.stats().calls, 1);
+    /// assert_eq!(writer.into_inner(), [0x12, 0x34, 0x56, 0x78, 0x78]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_value_coalesced<T>(&mut self, value: &T, endianness: Endianness) -> Result<()>
+    where
+        T: WriteTo + EncodedLen + ?Sized,
+    {
+        let mut buffer = Vec::with_capacity(value.encoded_len());
+        value.write_to(&mut buffer, endianness)?;
+        self.write_all(&buffer)
+    }
+
+    /// Write a slice of values of a type implementing [`WriteTo`] with a single call to
+    /// [`Write::write_all`], encoding numbers in the given byte order.
+    ///
+    /// For further semantics please refer to [`WriteValueExt::write_value_coalesced`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, MeteredWriter, WriteValueExt};
+    ///
+    /// let mut writer = MeteredWriter::new(Vec::new());
+    /// writer
+    ///     .write_values_coalesced(&[(1_u8, 2_u16), (3, 4)], Endianness::Little)
+    ///     .unwrap();
+    /// assert_eq!(writer.stats().calls, 1);
+    /// assert_eq!(writer.into_inner(), [0x01, 0x02, 0x00, 0x03, 0x04, 0x00]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_values_coalesced<T>(&mut self, values: &[T], endianness: Endianness) -> Result<()>
+    where
+        T: WriteTo + EncodedLen,
+    {
+        let mut buffer = Vec::with_capacity(values.iter().map(EncodedLen::encoded_len).sum());
+
+        for value in values {
+            value.write_to(&mut buffer, endianness)?;
+        }
+
+        self.write_all(&buffer)
+    }
+
+    /// Write the key/value pairs of a map, each key directly followed by its value.
+    ///
+    /// For further semantics please refer to [`Write::write_all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1_u8, 10_u16), (2, 20)]);
+    /// let mut bytes = Vec::new();
+    /// bytes.write_map(&map, Endianness::Big).unwrap();
+    /// assert_eq!(bytes, [0x01, 0x00, 0x0A, 0x02, 0x00, 0x14]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_map<'a, K, V, M>(&mut self, map: M, endianness: Endianness) -> Result<()>
+    where
+        K: WriteTo + ?Sized + 'a,
+        V: WriteTo + ?Sized + 'a,
+        M: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        for (key, value) in map {
+            key.write_to(self, endianness)?;
+            value.write_to(self, endianness)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the key/value pairs of a map, prefixed with their count as `P` in the given byte order.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the count does not fit into `P`.
+    ///
+    /// For further semantics please refer to [`WriteValueExt::write_map`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rw_exact_ext::{Endianness, WriteValueExt};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1_u8, 10_u16), (2, 20)]);
+    /// let mut bytes = Vec::new();
+    /// bytes.write_map_prefixed::<u8, _, _, _>(&map, Endianness::Little).unwrap();
+    /// assert_eq!(bytes, [0x02, 0x01, 0x0A, 0x00, 0x02, 0x14, 0x00]);
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn write_map_prefixed<'a, P, K, V, M>(&mut self, map: M, endianness: Endianness) -> Result<()>
+    where
+        P: WriteTo + TryFrom<usize>,
+        P::Error: std::error::Error + Send + Sync + 'static,
+        K: WriteTo + ?Sized + 'a,
+        V: WriteTo + ?Sized + 'a,
+        M: IntoIterator<Item = (&'a K, &'a V)>,
+        M::IntoIter: ExactSizeIterator,
+    {
+        let pairs = map.into_iter();
+        P::try_from(pairs.len())
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+            .write_to(self, endianness)?;
+        self.write_map(pairs, endianness)
+    }
+}
+
+impl<T> WriteValueExt for T where T: Write {}
+
+#[cfg(feature = "ndarray")]
+fn read_array2<R, T>(
+    reader: &mut R,
+    shape: (usize, usize),
+    endianness: Endianness,
+) -> Result<ndarray::Array2<T>>
+where
+    R: Read + ?Sized,
+    T: bytemuck::Pod + FromWireBytes,
+{
+    check_element_size::<T>()?;
+    let len = shape
+        .0
+        .checked_mul(shape.1)
+        .filter(|len| len.checked_mul(size_of::<T>()).is_some())
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "array shape too large"))?;
+    let mut values = vec![T::zeroed(); len];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut values);
+    reader.read_exact(bytes)?;
+
+    if !endianness.is_native() {
+        swap_bytes(bytes, size_of::<T>());
+    }
+
+    Ok(ndarray::Array2::from_shape_vec(shape, values).expect("shape should match element count"))
+}
+
+#[cfg(feature = "ndarray")]
+fn write_array2<W, S, T>(
+    writer: &mut W,
+    array: &ndarray::ArrayBase<S, ndarray::Ix2>,
+    endianness: Endianness,
+) -> Result<()>
+where
+    W: Write + ?Sized,
+    S: ndarray::Data<Elem = T>,
+    T: bytemuck::Pod + ToWireBytes,
+{
+    check_element_size::<T>()?;
+    let mut bytes = Vec::with_capacity(array.len() * size_of::<T>());
+
+    for value in array {
+        bytes.extend_from_slice(bytemuck::bytes_of(value));
+    }
+
+    if !endianness.is_native() {
+        swap_bytes(&mut bytes, size_of::<T>());
+    }
+
+    writer.write_all(&bytes)
+}
+
+/// Return an error of kind [`ErrorKind::InvalidInput`] if `T` is zero-sized.
+#[cfg(feature = "bytemuck")]
+fn check_element_size<T>() -> Result<()> {
+    if size_of::<T>() == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "zero-sized elements are not supported",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Minimum amount of bytes to byte-swap in parallel.
+#[cfg(all(feature = "ndarray", feature = "rayon"))]
+const PARALLEL_SWAP_SIZE: usize = 1024 * 1024;
+
+/// Reverse the bytes of each element of `size` bytes.
+#[cfg(feature = "ndarray")]
+fn swap_bytes(bytes: &mut [u8], size: usize) {
+    #[cfg(feature = "rayon")]
+    if bytes.len() >= PARALLEL_SWAP_SIZE {
+        use rayon::prelude::*;
+
+        bytes.par_chunks_exact_mut(size).for_each(<[u8]>::reverse);
+        return;
+    }
+
+    bytes.chunks_exact_mut(size).for_each(<[u8]>::reverse);
+}
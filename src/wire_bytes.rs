@@ -0,0 +1,119 @@
+use crate::Endianness;
+
+/// Numbers which can be decoded from a byte array.
+///
+/// This is implemented for all primitive integers and floats.
+/// With the feature `num-traits` enabled, it is also implemented for all types implementing
+/// `num_traits::FromBytes` and `num_traits::ToBytes`, such as the fixed-point numbers of `fixed`.
+/// The trait is sealed and cannot be implemented otherwise.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, FromWireBytes};
+///
+/// assert_eq!(u16::from_wire_bytes([0x13, 0x37], Endianness::Big), 0x1337);
+/// assert_eq!(i16::from_wire_bytes([0x13, 0x37], Endianness::Little), 0x3713);
+/// ```
+pub trait FromWireBytes: Sized + sealed::Sealed {
+    /// The byte array the number is decoded from.
+    type Bytes;
+
+    /// Decode a number from `bytes` in the given byte order.
+    fn from_wire_bytes(bytes: Self::Bytes, endianness: Endianness) -> Self;
+}
+
+/// Numbers which can be encoded as a byte array.
+///
+/// This is implemented for the same types as [`FromWireBytes`] and is sealed as well.
+///
+/// # Examples
+/// ```
+/// use rw_exact_ext::{Endianness, ToWireBytes};
+///
+/// assert_eq!(0x1337_u16.to_wire_bytes(Endianness::Big), [0x13, 0x37]);
+/// assert_eq!(1.5_f32.to_wire_bytes(Endianness::Little), [0x00, 0x00, 0xC0, 0x3F]);
+/// ```
+pub trait ToWireBytes: sealed::Sealed {
+    /// The byte array the number is encoded as.
+    type Bytes;
+
+    /// Encode the number as bytes in the given byte order.
+    fn to_wire_bytes(&self, endianness: Endianness) -> Self::Bytes;
+}
+
+#[cfg(not(feature = "num-traits"))]
+macro_rules! impl_wire_bytes {
+    ($($num:ty),*) => {
+        $(
+            impl sealed::Sealed for $num {}
+
+            impl FromWireBytes for $num {
+                type Bytes = [u8; size_of::<$num>()];
+
+                fn from_wire_bytes(bytes: Self::Bytes, endianness: Endianness) -> Self {
+                    match endianness {
+                        Endianness::Big => Self::from_be_bytes(bytes),
+                        Endianness::Little => Self::from_le_bytes(bytes),
+                        Endianness::Native => Self::from_ne_bytes(bytes),
+                    }
+                }
+            }
+
+            impl ToWireBytes for $num {
+                type Bytes = [u8; size_of::<$num>()];
+
+                fn to_wire_bytes(&self, endianness: Endianness) -> Self::Bytes {
+                    match endianness {
+                        Endianness::Big => self.to_be_bytes(),
+                        Endianness::Little => self.to_le_bytes(),
+                        Endianness::Native => self.to_ne_bytes(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl_wire_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(feature = "num-traits")]
+impl<N> sealed::Sealed for N where N: num_traits::ToBytes {}
+
+#[cfg(feature = "num-traits")]
+impl<N> FromWireBytes for N
+where
+    N: num_traits::FromBytes + num_traits::ToBytes,
+    <N as num_traits::FromBytes>::Bytes: Sized,
+{
+    type Bytes = <N as num_traits::FromBytes>::Bytes;
+
+    fn from_wire_bytes(bytes: Self::Bytes, endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::Big => Self::from_be_bytes(&bytes),
+            Endianness::Little => Self::from_le_bytes(&bytes),
+            Endianness::Native => Self::from_ne_bytes(&bytes),
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<N> ToWireBytes for N
+where
+    N: num_traits::ToBytes,
+    <N as num_traits::ToBytes>::Bytes: Sized,
+{
+    type Bytes = <N as num_traits::ToBytes>::Bytes;
+
+    fn to_wire_bytes(&self, endianness: Endianness) -> Self::Bytes {
+        match endianness {
+            Endianness::Big => self.to_be_bytes(),
+            Endianness::Little => self.to_le_bytes(),
+            Endianness::Native => self.to_ne_bytes(),
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}